@@ -1,4 +1,4 @@
-use base64::Engine;
+use std::collections::HashSet;
 use std::{fs, io, path};
 
 static SVG_MOD: &str = include_str!("src/_templates/svg_mod.rs");
@@ -9,33 +9,245 @@ fn get_constant_name(name: &str) -> String {
     name.to_uppercase().replace("-", "_")
 }
 
+/// Namespace of the SVG elements and attributes kept by [`minify_svg`].
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+/// Namespace of `xlink:href` and friends.
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+/// Namespace of `xml:space` and friends.
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Escape text for use in XML content or a double quoted attribute.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Collapse every run of whitespace into a single space, and trim both ends.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Minify a `style` attribute, dropping empty and editor specific declarations.
+fn minify_style(style: &str) -> String {
+    style
+        .split(';')
+        .map(collapse_whitespace)
+        .filter(|declaration| !declaration.is_empty() && !declaration.starts_with("-inkscape"))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Get the IDs referenced by an attribute value, as `url(#id)` or `#id`.
+fn references(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split("url(#")
+        .skip(1)
+        .filter_map(|reference| reference.split(')').next())
+        .chain(value.strip_prefix('#'))
+}
+
+/// Whether an element is a definition that nothing refers to.
+fn is_unused_definition(node: roxmltree::Node, referenced: &HashSet<String>) -> bool {
+    node.parent_element()
+        .is_some_and(|parent| parent.tag_name().name() == "defs")
+        && node
+            .attribute("id")
+            .is_none_or(|id| !referenced.contains(id))
+}
+
+/// Get the IDs referenced from the document, ignoring references made only by
+/// unused definitions.
+fn referenced_ids(document: &roxmltree::Document) -> HashSet<String> {
+    let mut referenced = HashSet::new();
+    loop {
+        let next = document
+            .descendants()
+            .filter(|node| {
+                node.is_element()
+                    && !node.ancestors().any(|ancestor| {
+                        ancestor.is_element() && is_unused_definition(ancestor, &referenced)
+                    })
+            })
+            .flat_map(|node| node.attributes())
+            .flat_map(|attribute| references(attribute.value()))
+            .map(String::from)
+            .collect::<HashSet<_>>();
+
+        // Each pass can only drop references, so this settles.
+        if next == referenced {
+            return referenced;
+        }
+        referenced = next;
+    }
+}
+
+/// Write an SVG element and its children, leaving out anything that is not SVG and
+/// any unused definitions and IDs.
+fn write_element(node: roxmltree::Node, referenced: &HashSet<String>, output: &mut String) {
+    let tag_name = node.tag_name();
+    if tag_name.namespace() != Some(SVG_NAMESPACE)
+        || tag_name.name() == "metadata"
+        || is_unused_definition(node, referenced)
+    {
+        return;
+    }
+
+    output.push('<');
+    output.push_str(tag_name.name());
+    if node.parent_element().is_none() {
+        output.push_str(&format!(r#" xmlns="{SVG_NAMESPACE}""#));
+        if node.descendants().any(|child| {
+            child
+                .attributes()
+                .any(|attribute| attribute.namespace() == Some(XLINK_NAMESPACE))
+        }) {
+            output.push_str(&format!(r#" xmlns:xlink="{XLINK_NAMESPACE}""#));
+        }
+    }
+
+    for attribute in node.attributes() {
+        let prefix = match attribute.namespace() {
+            None => "",
+            Some(XLINK_NAMESPACE) => "xlink:",
+            Some(XML_NAMESPACE) => "xml:",
+            Some(_) => continue,
+        };
+        let value = match attribute.name() {
+            "style" => minify_style(attribute.value()),
+            _ => collapse_whitespace(attribute.value()),
+        };
+        match attribute.name() {
+            "style" if value.is_empty() => continue,
+            "id" if !referenced.contains(&value) => continue,
+            _ => {}
+        }
+
+        output.push_str(&format!(
+            r#" {prefix}{}="{}""#,
+            attribute.name(),
+            escape_xml(&value)
+        ));
+    }
+
+    let start = output.len();
+    for child in node.children() {
+        if child.is_element() {
+            write_element(child, referenced, output);
+        } else if let Some(text) = child.text().filter(|text| !text.trim().is_empty()) {
+            if child.is_text() {
+                output.push_str(&escape_xml(text));
+            }
+        }
+    }
+
+    if output.len() == start {
+        output.push_str("/>");
+    } else {
+        output.insert(start, '>');
+        output.push_str(&format!("</{}>", tag_name.name()));
+    }
+}
+
+/// Minify SVG markup by dropping comments, metadata, editor namespaces and
+/// insignificant whitespace.
+fn minify_svg(source: &str) -> Result<String, roxmltree::Error> {
+    let document = roxmltree::Document::parse_with_options(
+        source,
+        roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        },
+    )?;
+
+    let referenced = referenced_ids(&document);
+    let mut output = String::with_capacity(source.len());
+    write_element(document.root_element(), &referenced, &mut output);
+    Ok(output)
+}
+
+/// Compress minified markup for embedding.
+#[cfg(feature = "compressed-svg")]
+fn compress(markup: &str) -> io::Result<Vec<u8>> {
+    use io::Write;
+
+    let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(markup.as_bytes())?;
+    encoder.finish()
+}
+
+/// The total size of a theme at each stage of embedding, in bytes.
+#[derive(Default)]
+struct SizeReport {
+    source: usize,
+    minified: usize,
+    embedded: usize,
+}
+
+impl std::ops::AddAssign for SizeReport {
+    fn add_assign(&mut self, other: Self) {
+        self.source += other.source;
+        self.minified += other.minified;
+        self.embedded += other.embedded;
+    }
+}
+
 struct SvgDeclaration {
     path: path::PathBuf,
-    svg_base64: String,
+    embedded: Option<path::PathBuf>,
 }
 
 impl SvgDeclaration {
     /// Create a new SVG declaration.
     pub fn new(path: path::PathBuf) -> Self {
-        let svg_base64 = base64::engine::general_purpose::STANDARD.encode(
-            std::fs::read_to_string(&path)
-                .unwrap_or_else(|_| panic!("Could not read the contents of the file {path:?}.")),
-        );
-        Self { path, svg_base64 }
+        Self {
+            path,
+            embedded: None,
+        }
+    }
+
+    /// Whether the embedded asset is compressed.
+    fn is_compressed() -> bool {
+        cfg!(feature = "compressed-svg")
     }
 
     /// Convert a name to a constant format, which is uppercase and uses underscores.
     pub fn constant_name(&self) -> io::Result<String> {
-        let path = &self.path;
-        Ok(get_constant_name(
-            &path
-                .file_stem()
-                .ok_or(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("{path:?} does not have a valid stem."),
-                ))?
-                .to_string_lossy(),
-        ))
+        Ok(get_constant_name(&self.file_stem()?))
+    }
+
+    /// Minify the SVG, compressing it if enabled, and write it into `asset_dir` for
+    /// embedding.
+    pub fn embed(&mut self, asset_dir: &path::Path) -> io::Result<SizeReport> {
+        let source = fs::read_to_string(&self.path)?;
+        let minified = minify_svg(&source).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{:?} is not valid SVG: {err}", self.path),
+            )
+        })?;
+
+        let file_stem = self.file_stem()?;
+        #[cfg(feature = "compressed-svg")]
+        let (embedded, contents) = (
+            asset_dir.join(format!("{file_stem}.svg.deflate")),
+            compress(&minified)?,
+        );
+        #[cfg(not(feature = "compressed-svg"))]
+        let (embedded, contents) = (
+            asset_dir.join(format!("{file_stem}.svg")),
+            minified.clone().into_bytes(),
+        );
+
+        fs::write(&embedded, &contents)?;
+        self.embedded = Some(embedded);
+
+        Ok(SizeReport {
+            source: source.len(),
+            minified: minified.len(),
+            embedded: contents.len(),
+        })
     }
 
     /// Get the declaration of the SVG.
@@ -49,33 +261,112 @@ impl SvgDeclaration {
             ))?
             .to_string_lossy();
         let constant_name = self.constant_name()?;
-        let svg_base64 = &self.svg_base64;
-        Ok(format!(
-            "/// Base64 encoded SVG contents, generated from {file_name}.\npub const {constant_name}: &str = \"data:image/svg+xml;base64,{svg_base64}\";",
-        ))
+        let embedded_path = self
+            .embedded
+            .as_ref()
+            .expect("The SVG should be embedded before it is declared.")
+            .to_string_lossy()
+            .to_string();
+
+        Ok(if Self::is_compressed() {
+            format!(
+                "/// Deflated SVG markup, minified from {file_name}.\nconst {constant_name}: &[u8] = include_bytes!({embedded_path:?});",
+            )
+        } else {
+            format!(
+                "/// Raw SVG markup, minified from {file_name}.\nconst {constant_name}: &str = include_str!({embedded_path:?});",
+            )
+        })
     }
 
-    /// Match the SVG name stem to a declaration.
-    pub fn match_to_declaration(&self) -> io::Result<String> {
+    /// Get the SVG name stem of the declaration.
+    fn file_stem(&self) -> io::Result<String> {
         let path = &self.path;
-        let file_stem = path
+        Ok(path
             .file_stem()
             .ok_or(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 format!("{path:?} does not have a valid file stem."),
             ))?
-            .to_string_lossy();
-        let constant_name = self.constant_name()?;
+            .to_string_lossy()
+            .to_string())
+    }
+
+    /// Match the SVG name stem to the markup at `index` in the template's `SOURCES`.
+    pub fn match_to_markup(&self, index: usize) -> io::Result<String> {
+        let file_stem = self.file_stem()?;
 
-        Ok(format!("        {file_stem:?} => Some({constant_name}),",))
+        Ok(format!("        {file_stem:?} => Some(markup({index})),"))
     }
+
+    /// Match the SVG name stem to the cached data URI at `index` in `SOURCES`.
+    pub fn match_to_data_uri(&self, index: usize) -> io::Result<String> {
+        let file_stem = self.file_stem()?;
+
+        Ok(format!("        {file_stem:?} => Some(data_uri({index})),"))
+    }
+}
+
+/// The SVG names of the tiles, without the extension, in the order of `Tile::id`.
+fn tile_svg_names() -> Vec<String> {
+    let numbers = ["wan", "tong", "tiao"]
+        .iter()
+        .flat_map(|category| (1..=9).map(move |value| format!("{category}-{value}")));
+    let named = [
+        (
+            "honour",
+            &[
+                "east",
+                "south",
+                "west",
+                "north",
+                "blank",
+                "prosperity",
+                "central",
+            ][..],
+        ),
+        ("flower", &["plum", "orchid", "chrysanthemum", "bamboo"][..]),
+        ("season", &["spring", "summer", "autumn", "winter"][..]),
+    ]
+    .into_iter()
+    .flat_map(|(category, names)| names.iter().map(move |name| format!("{category}-{name}")));
+
+    numbers.chain(named).collect()
 }
 
-/// Generate a svg template.
-fn generate_svg_template(source: path::PathBuf, output_dir: &path::Path) -> io::Result<String> {
+/// The SVG names every theme has to provide, without the extension.
+fn required_svg_names() -> Vec<String> {
+    let base = ["front", "back", "blank"]
+        .iter()
+        .map(|name| format!("base-{name}"));
+
+    tile_svg_names().into_iter().chain(base).collect()
+}
+
+/// Generate the table of tile SVG names included by `Tile::svg_name`.
+fn generate_tile_names(output_dir: &path::Path) -> io::Result<()> {
+    let names = tile_svg_names()
+        .iter()
+        .map(|name| format!("    {name:?},"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    fs::write(
+        output_dir.join("tile_names.rs"),
+        format!(
+            "/// The SVG names of the tiles, indexed by [`Tile::id`].\nconst SVG_NAMES: [&str; Tile::KINDS as usize] = [\n{names}\n];\n"
+        ),
+    )
+}
+
+/// Generate a svg template for the theme in `source`, as the module `mod_name`.
+fn generate_svg_template(
+    source: &path::Path,
+    mod_name: &str,
+    output_dir: &path::Path,
+) -> io::Result<SizeReport> {
     let canonicalized_source = source.canonicalize()?;
     let source_path = canonicalized_source.to_string_lossy();
-    let mod_name = source.file_stem().unwrap().to_string_lossy().to_string();
 
     let mut declarations = fs::read_dir(source)?
         .filter_map(|entry| {
@@ -89,14 +380,50 @@ fn generate_svg_template(source: path::PathBuf, output_dir: &path::Path) -> io::
         .map(SvgDeclaration::new)
         .collect::<Vec<SvgDeclaration>>();
 
-    assert_eq!(declarations.len(), 37, "There should be 37 SVG files.");
-
     declarations.sort_by_key(|declaration| {
         declaration
             .constant_name()
             .expect("Could not get the constant name.")
     });
 
+    let stems = declarations
+        .iter()
+        .map(|declaration| declaration.file_stem())
+        .collect::<io::Result<Vec<_>>>()?;
+    let missing = required_svg_names()
+        .into_iter()
+        .filter(|name| !stems.contains(name))
+        .map(|name| format!("{name}.svg"))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        panic!(
+            "The theme at {source_path} is missing {} SVG files: {}",
+            missing.len(),
+            missing.join(", ")
+        );
+    }
+
+    let asset_dir = output_dir.join(mod_name);
+    if !asset_dir.exists() {
+        fs::create_dir(&asset_dir)?;
+    }
+    let mut sizes = SizeReport::default();
+    for declaration in declarations.iter_mut() {
+        sizes += declaration.embed(&asset_dir)?;
+    }
+
+    let tile_indices = tile_svg_names()
+        .iter()
+        .map(|name| {
+            let index = stems
+                .iter()
+                .position(|stem| stem == name)
+                .expect("Every tile should have an SVG.");
+            format!("    {index},")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
     let template = SVG_TEMPLATE
         .replace("{{SOURCE_PATH}}", &source_path)
         .replace(
@@ -109,10 +436,57 @@ fn generate_svg_template(source: path::PathBuf, output_dir: &path::Path) -> io::
                 .join("\n\n"),
         )
         .replace(
-            "{{MATCHES}}",
+            "{{MARKUP_CACHE}}",
+            if SvgDeclaration::is_compressed() {
+                "\n/// SVG markup, decompressed on first use.\nstatic MARKUPS: [OnceLock<String>; {{COUNT}}] = [const { OnceLock::new() }; {{COUNT}}];\n"
+            } else {
+                ""
+            },
+        )
+        .replace(
+            "{{SOURCE_TYPE}}",
+            if SvgDeclaration::is_compressed() {
+                "&[u8]"
+            } else {
+                "&str"
+            },
+        )
+        .replace(
+            "{{SOURCES}}",
             &declarations
                 .iter()
-                .map(|declaration| declaration.match_to_declaration())
+                .map(|declaration| Ok(format!("    {},", declaration.constant_name()?)))
+                .collect::<io::Result<Vec<_>>>()?
+                .as_slice()
+                .join("\n"),
+        )
+        .replace(
+            "{{MARKUP}}",
+            if SvgDeclaration::is_compressed() {
+                "super::inflate_markup(&MARKUPS[index], SOURCES[index])"
+            } else {
+                "SOURCES[index]"
+            },
+        )
+        .replace("{{TILE_INDICES}}", &tile_indices)
+        .replace("{{COUNT}}", &declarations.len().to_string())
+        .replace("{{TILE_COUNT}}", &tile_svg_names().len().to_string())
+        .replace(
+            "{{MARKUP_MATCHES}}",
+            &declarations
+                .iter()
+                .enumerate()
+                .map(|(index, declaration)| declaration.match_to_markup(index))
+                .collect::<io::Result<Vec<_>>>()?
+                .as_slice()
+                .join("\n"),
+        )
+        .replace(
+            "{{DATA_URI_MATCHES}}",
+            &declarations
+                .iter()
+                .enumerate()
+                .map(|(index, declaration)| declaration.match_to_data_uri(index))
                 .collect::<io::Result<Vec<_>>>()?
                 .as_slice()
                 .join("\n"),
@@ -120,30 +494,128 @@ fn generate_svg_template(source: path::PathBuf, output_dir: &path::Path) -> io::
 
     std::fs::write(output_dir.join(format!("{mod_name}.rs")), template)?;
 
-    Ok(mod_name)
+    Ok(sizes)
+}
+
+/// Get the module name for a theme directory, which is its lowercased name with
+/// anything other than letters and digits replaced by underscores.
+fn theme_module_name(source: &path::Path) -> String {
+    let name = source
+        .file_name()
+        .unwrap_or_else(|| panic!("{source:?} does not name a theme directory."))
+        .to_string_lossy()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        panic!("The theme directory name {source:?} must start with a letter.");
+    }
+    name
+}
+
+/// Get the themes to embed, as pairs of module name and source directory.
+///
+/// The built-in themes are enabled by the `theme-*` features, and any directories
+/// listed in `MAHJONG_TILES_EXTRA_THEMES` are added after them.
+fn themes() -> Vec<(String, path::PathBuf)> {
+    let mut themes = ["dark", "light"]
+        .into_iter()
+        .filter(|theme| {
+            std::env::var_os(format!("CARGO_FEATURE_THEME_{}", get_constant_name(theme))).is_some()
+        })
+        .map(|theme| {
+            (
+                theme.to_string(),
+                path::PathBuf::from(format!("svg/{theme}")),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(extra) = std::env::var_os("MAHJONG_TILES_EXTRA_THEMES") {
+        std::env::split_paths(&extra)
+            .filter(|source| !source.as_os_str().is_empty())
+            .for_each(|source| {
+                let name = theme_module_name(&source);
+                if ["dark", "light"].contains(&name.as_str())
+                    || themes.iter().any(|(existing, _)| *existing == name)
+                {
+                    panic!("The theme directory {source:?} clashes with the {name} theme.");
+                }
+                if !source.is_dir() {
+                    panic!("The theme directory {source:?} does not exist.");
+                }
+
+                println!("cargo::rerun-if-changed={}", source.display());
+                themes.push((name, source));
+            });
+    }
+
+    themes
+}
+
+/// Generate the C header for the `ffi` module into `out_dir`.
+///
+/// The copy committed in `include/` is checked against it by the `ffi` tests.
+#[cfg(feature = "ffi")]
+fn generate_c_header(out_dir: &path::Path) {
+    println!("cargo::rerun-if-changed=src/ffi.rs");
+    println!("cargo::rerun-if-changed=cbindgen.toml");
+
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("Cargo should set `CARGO_MANIFEST_DIR` for build scripts.");
+    let config = cbindgen::Config::from_file(path::Path::new(&crate_dir).join("cbindgen.toml"))
+        .expect("cbindgen.toml should be a valid cbindgen configuration.");
+
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(path::Path::new(&crate_dir).join("src/ffi.rs"))
+        .generate()
+        .expect("The C header should generate from src/ffi.rs.")
+        .write_to_file(out_dir.join("mahjong_tiles.h"));
 }
 
 // Example custom build script.
 fn main() -> io::Result<()> {
     // Tell Cargo that if the SVG file change, to rerun this build script.
-    println!("cargo::rerun-if-changed=svg/dark/*.svg");
-    println!("cargo::rerun-if-changed=svg/light/*.svg");
+    println!("cargo::rerun-if-changed=svg");
+    println!("cargo::rerun-if-changed=src/_templates/svg_mod.rs");
     println!("cargo::rerun-if-changed=src/_templates/svg_template.rs");
     println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed=MAHJONG_TILES_EXTRA_THEMES");
+    println!("cargo::rerun-if-env-changed=MAHJONG_TILES_SIZE_REPORT");
 
     let out_dir = std::env::var("OUT_DIR").expect(
         "Failed to get the output directory. Please make sure that the environment variable `OUT_DIR` is set.",
     );
 
+    generate_tile_names(path::Path::new(&out_dir))?;
+
     let svg_dir = path::PathBuf::from(&out_dir).join("svg");
     if !svg_dir.exists() {
         fs::create_dir(&svg_dir)?;
     }
-    let module_names = ["dark", "light"]
-        .iter()
-        .map(|theme| {
-            let source = path::PathBuf::from(format!("svg/{}", theme));
-            generate_svg_template(source, &svg_dir)
+    let module_names = themes()
+        .into_iter()
+        .map(|(name, source)| {
+            let sizes = generate_svg_template(&source, &name, &svg_dir)?;
+            if std::env::var_os("MAHJONG_TILES_SIZE_REPORT").is_some() {
+                println!(
+                    "cargo::warning=The {name} theme is {} bytes, minified from {} bytes ({:.1}% saved), and embedded as {} bytes ({:.1}% saved).",
+                    sizes.minified,
+                    sizes.source,
+                    100.0 * (1.0 - sizes.minified as f64 / sizes.source as f64),
+                    sizes.embedded,
+                    100.0 * (1.0 - sizes.embedded as f64 / sizes.source as f64),
+                );
+            }
+            Ok(name)
         })
         .collect::<io::Result<Vec<String>>>()?;
 
@@ -165,7 +637,28 @@ fn main() -> io::Result<()> {
                     format!("    /// SVG style for the {name} theme.\n    {constant_name},")
                 })
                 .collect::<Vec<String>>()
-                .join(""),
+                .join("\n"),
+        )
+        .replace(
+            "{{ALL}}",
+            &module_names
+                .iter()
+                .map(|name| format!("        SvgStyle::{},", get_constant_name(name)))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )
+        .replace(
+            "{{NAME_MATCH}}",
+            &module_names
+                .iter()
+                .map(|name| {
+                    format!(
+                        "            SvgStyle::{} => \"{name}\",",
+                        get_constant_name(name)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
         )
         .replace(
             "{{MATCH}}",
@@ -180,9 +673,52 @@ fn main() -> io::Result<()> {
                 })
                 .collect::<Vec<String>>()
                 .join("\n"),
+        )
+        .replace(
+            "{{MARKUP_MATCH}}",
+            &module_names
+                .iter()
+                .map(|name| {
+                    let constant_name = get_constant_name(name);
+                    format!(
+                        "            SvgStyle::{} => {name}::get_svg_markup_by_name(name),",
+                        constant_name
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )
+        .replace(
+            "{{ID_MATCH}}",
+            &module_names
+                .iter()
+                .map(|name| {
+                    format!(
+                        "            SvgStyle::{} => {name}::get_svg_by_id(id),",
+                        get_constant_name(name)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )
+        .replace(
+            "{{MARKUP_ID_MATCH}}",
+            &module_names
+                .iter()
+                .map(|name| {
+                    format!(
+                        "            SvgStyle::{} => {name}::get_svg_markup_by_id(id),",
+                        get_constant_name(name)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
         );
 
     std::fs::write(svg_dir.join("mod.rs"), template)?;
 
+    #[cfg(feature = "ffi")]
+    generate_c_header(path::Path::new(&out_dir));
+
     Ok(())
 }
@@ -0,0 +1,118 @@
+/* C API for the mahjong_tiles crate; link with -lmahjong_tiles. */
+
+#ifndef MAHJONG_TILES_H
+#define MAHJONG_TILES_H
+
+/* Generated from src/ffi.rs by build.rs with the `ffi` feature; do not edit. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// Returned in place of a tile identifier that is not valid.
+#define MAHJONG_INVALID_TILE 255
+
+// The result of a call to the C API.
+typedef enum MahjongStatus {
+  // The call succeeded.
+  MAHJONG_STATUS_OK = 0,
+  // A pointer is null, or a string is not UTF-8.
+  MAHJONG_STATUS_INVALID_ARGUMENT = -1,
+  // A tile identifier is out of range.
+  MAHJONG_STATUS_INVALID_TILE = -2,
+  // Hand notation could not be parsed.
+  MAHJONG_STATUS_INVALID_NOTATION = -3,
+  // The hand has a number of tiles that cannot be analysed.
+  MAHJONG_STATUS_INVALID_SIZE = -4,
+  // The hand holds more than four copies of a tile.
+  MAHJONG_STATUS_TOO_MANY_COPIES = -5,
+  // The output buffer is too small; the length needed has been written.
+  MAHJONG_STATUS_BUFFER_TOO_SMALL = -6,
+} MahjongStatus;
+
+// Returns the identifier of the tile with the given name in Chinese or Japanese, or
+// in mpsz notation, or `MAHJONG_INVALID_TILE`.
+//
+// # Safety
+//
+// `name` must be null or point to a NUL-terminated string.
+uint8_t mahjong_tile_from_name(const char *name);
+
+// Returns the number on a tile, or 0 for honours, flowers and invalid identifiers.
+uint8_t mahjong_tile_value(uint8_t tile);
+
+// Returns whether a tile is a wind or a dragon.
+bool mahjong_tile_is_honour(uint8_t tile);
+
+// Returns whether a tile is a flower or a season.
+bool mahjong_tile_is_flower(uint8_t tile);
+
+// Writes the SVG name of a tile, e.g. `tong-5`, NUL-terminated.
+//
+// # Safety
+//
+// `buffer` must point to `capacity` writable bytes, and `len` must be writable.
+enum MahjongStatus mahjong_tile_svg_name(uint8_t tile, char *buffer, size_t capacity, size_t *len);
+
+// Returns the SVG data URI for a tile or base layer by its SVG name, writing its
+// length to `len`, or null if the style or name is unknown.
+//
+// The data is static and is not NUL-terminated.
+//
+// # Safety
+//
+// `style` and `name` must be null or point to NUL-terminated strings, and `len` must
+// be null or writable.
+const char *mahjong_svg_data(const char *style, const char *name, size_t *len);
+
+// Returns the SVG data URI for a tile, as `mahjong_svg_data`.
+//
+// # Safety
+//
+// `style` must be null or point to a NUL-terminated string, and `len` must be null
+// or writable.
+const char *mahjong_tile_svg_data(uint8_t tile, const char *style, size_t *len);
+
+// Parses a hand in mpsz notation into tile identifiers.
+//
+// # Safety
+//
+// `notation` must point to a NUL-terminated string, `tiles` to `capacity` writable
+// bytes, and `len` must be writable.
+enum MahjongStatus mahjong_parse_hand(const char *notation,
+                                      uint8_t *tiles,
+                                      size_t capacity,
+                                      size_t *len);
+
+// Formats tile identifiers as a hand in mpsz notation, NUL-terminated.
+//
+// # Safety
+//
+// `tiles` must point to `count` bytes, `buffer` to `capacity` writable bytes, and
+// `len` must be writable.
+enum MahjongStatus mahjong_format_hand(const uint8_t *tiles,
+                                       size_t count,
+                                       char *buffer,
+                                       size_t capacity,
+                                       size_t *len);
+
+// Writes the shanten of a hand: 0 if it is ready, and -1 if it is complete.
+//
+// # Safety
+//
+// `tiles` must point to `count` bytes, and `shanten` must be writable.
+enum MahjongStatus mahjong_shanten(const uint8_t *tiles, size_t count, int8_t *shanten);
+
+// Writes the identifiers of the tiles that would complete a ready hand.
+//
+// # Safety
+//
+// `tiles` must point to `count` bytes, `waits` to `capacity` writable bytes, and
+// `len` must be writable.
+enum MahjongStatus mahjong_waits(const uint8_t *tiles,
+                                 size_t count,
+                                 uint8_t *waits,
+                                 size_t capacity,
+                                 size_t *len);
+
+#endif  /* MAHJONG_TILES_H */
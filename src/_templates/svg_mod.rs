@@ -1,32 +1,240 @@
+use std::sync::OnceLock;
+
+use base64::Engine;
+
 use crate::models::IsTile;
+use crate::theme::TileTheme;
 
 {{MODULES}}
 
+/// Prefix of the data URIs returned by [`SvgStyle::get_svg_by_name`].
+pub const DATA_URI_PREFIX: &str = "data:image/svg+xml;base64,";
+
+/// Encode SVG markup as a base64 data URI.
+pub(crate) fn encode_data_uri(markup: &str) -> String {
+    format!(
+        "{DATA_URI_PREFIX}{}",
+        base64::engine::general_purpose::STANDARD.encode(markup)
+    )
+}
+
+/// Get the base64 data URI for SVG markup, encoding it only on first use.
+#[allow(dead_code, reason = "Unused when no themes are embedded.")]
+fn cached_data_uri(cache: &'static OnceLock<String>, markup: &str) -> &'static str {
+    cache.get_or_init(|| encode_data_uri(markup))
+}
+
+/// Get the SVG markup from its deflated form, decompressing it only on first use.
+#[cfg(feature = "compressed-svg")]
+#[allow(dead_code, reason = "Unused when no themes are embedded.")]
+fn inflate_markup(cache: &'static OnceLock<String>, data: &[u8]) -> &'static str {
+    cache.get_or_init(|| {
+        let mut markup = String::new();
+        std::io::Read::read_to_string(&mut flate2::read::DeflateDecoder::new(data), &mut markup)
+            .expect("Embedded SVG markup should decompress.");
+        markup
+    })
+}
+
 /// Style for SVG elements.
+///
+/// `DARK` and `LIGHT` are enabled by the `theme-dark` and `theme-light` features;
+/// directories listed in `MAHJONG_TILES_EXTRA_THEMES` at build time are added as
+/// further variants, named after the directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types, reason = "Variants are named after theme directories.")]
 pub enum SvgStyle {
 {{STYLES}}
 }
 
+/// The layers shared by all tiles, drawn underneath or in place of the tile glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BaseLayer {
+    /// The face of a tile, without a glyph.
+    FRONT,
+    /// The back of a tile.
+    BACK,
+    /// A question mark glyph, for a tile that is not known.
+    BLANK,
+}
+
+impl BaseLayer {
+    /// Get the SVG name for this layer.
+    pub fn svg_name(&self) -> &'static str {
+        match self {
+            BaseLayer::FRONT => "base-front",
+            BaseLayer::BACK => "base-back",
+            BaseLayer::BLANK => "base-blank",
+        }
+    }
+}
+
 /// SVG data getting mapped to a style.
 impl SvgStyle {
-    /// Get the SVG data for a tile.
+    /// Every style embedded in this build.
+    pub const ALL: &'static [SvgStyle] = &[
+{{ALL}}
+    ];
+
+    /// Get the name of this style, which is the name of its theme directory.
+    pub fn name(&self) -> &'static str {
+        match *self {
+{{NAME_MATCH}}
+        }
+    }
+
+    /// Get the style with the given name, as returned by [`SvgStyle::name`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|style| style.name() == name)
+    }
+
+    /// Get the SVG data for a tile, as a base64 data URI.
+    #[allow(unused_variables, reason = "Unused when no themes are embedded.")]
     pub fn get_svg_by_name(&self, name: &str) -> Option<&'static str> {
-        match self {
+        match *self {
 {{MATCH}}
         }
     }
+
+    /// Get the raw SVG markup for a tile.
+    #[allow(unused_variables, reason = "Unused when no themes are embedded.")]
+    pub fn get_svg_markup_by_name(&self, name: &str) -> Option<&'static str> {
+        match *self {
+{{MARKUP_MATCH}}
+        }
+    }
+
+    /// Get the SVG data for the tile with the given [`Tile::id`](crate::Tile::id), as a
+    /// base64 data URI.
+    ///
+    /// Unlike [`SvgStyle::get_svg_by_name`], this is an array lookup.
+    #[allow(unused_variables, reason = "Unused when no themes are embedded.")]
+    pub fn get_svg_by_id(&self, id: u8) -> Option<&'static str> {
+        match *self {
+{{ID_MATCH}}
+        }
+    }
+
+    /// Get the raw SVG markup for the tile with the given [`Tile::id`](crate::Tile::id).
+    #[allow(unused_variables, reason = "Unused when no themes are embedded.")]
+    pub fn get_svg_markup_by_id(&self, id: u8) -> Option<&'static str> {
+        match *self {
+{{MARKUP_ID_MATCH}}
+        }
+    }
+
+    /// Get the SVG data for a base layer.
+    pub fn base_layer(&self, layer: BaseLayer) -> &'static str {
+        self.get_svg_by_name(layer.svg_name())
+            .expect("Every style should have all the base layers.")
+    }
 }
 
 /// Trait for tiles that have SVG data.
 pub trait HasSvgData: IsTile {
     /// Get the SVG data for this tile.
+    ///
+    /// This is the glyph layer only, with a transparent background; draw it over
+    /// [`BaseLayer::FRONT`], or use [`crate::render::compose_tile`], for a whole tile.
     fn svg_data(&self, style: SvgStyle) -> Option<&'static str>;
+
+    /// Get the raw SVG markup for this tile, e.g. to inline as an `<svg>` element.
+    ///
+    /// Like [`HasSvgData::svg_data`], this is the glyph layer only.
+    fn svg_markup(&self, style: SvgStyle) -> Option<&'static str>;
+
+    /// Get the SVG data for this tile from any [`TileTheme`], such as a
+    /// [`CustomTheme`](crate::theme::CustomTheme) loaded at runtime.
+    fn theme_svg_data<'t>(&self, theme: &'t dyn TileTheme) -> Option<&'t str>;
+
+    /// Get the raw SVG markup for this tile from any [`TileTheme`].
+    fn theme_svg_markup<'t>(&self, theme: &'t dyn TileTheme) -> Option<&'t str>;
 }
 
 /// Add method to retrieve SVG data to all structs that implement [`IsTile`].
 impl<T> HasSvgData for T where T: IsTile {
     /// Get the SVG data for this tile.
     fn svg_data(&self, style: SvgStyle) -> Option<&'static str> {
-        style.get_svg_by_name(&self.svg_name())
+        self.checked_id().and_then(|id| style.get_svg_by_id(id))
+    }
+
+    /// Get the raw SVG markup for this tile.
+    fn svg_markup(&self, style: SvgStyle) -> Option<&'static str> {
+        self.checked_id().and_then(|id| style.get_svg_markup_by_id(id))
+    }
+
+    /// Get the SVG data for this tile from a theme.
+    fn theme_svg_data<'t>(&self, theme: &'t dyn TileTheme) -> Option<&'t str> {
+        self.svg_name().and_then(|name| theme.svg_data_by_name(name))
+    }
+
+    /// Get the raw SVG markup for this tile from a theme.
+    fn theme_svg_markup<'t>(&self, theme: &'t dyn TileTheme) -> Option<&'t str> {
+        self.svg_name().and_then(|name| theme.svg_markup_by_name(name))
+    }
+}
+
+#[cfg(all(test, feature = "theme-dark", feature = "theme-light"))]
+mod test_svg_data {
+    use super::*;
+    use crate::models::Tile;
+
+    macro_rules! create_test {
+        ($name:ident, $tile:expr, $style:expr) => {
+            #[test]
+            fn $name() {
+                let markup = $tile.svg_markup($style).unwrap();
+                let data = $tile.svg_data($style).unwrap();
+
+                assert!(markup.contains("<svg"));
+                assert_eq!(
+                    data,
+                    format!(
+                        "{DATA_URI_PREFIX}{}",
+                        base64::engine::general_purpose::STANDARD.encode(markup)
+                    )
+                );
+                assert!(std::ptr::eq(data, $tile.svg_data($style).unwrap()));
+            }
+        };
+    }
+
+    create_test!(tong1_dark, Tile::TONG(1), SvgStyle::DARK);
+    create_test!(east_light, Tile::EAST, SvgStyle::LIGHT);
+
+    #[test]
+    fn unknown_name() {
+        assert_eq!(SvgStyle::DARK.get_svg_markup_by_name("tong-10"), None);
+        assert_eq!(SvgStyle::DARK.get_svg_by_name("tong-10"), None);
+        assert_eq!(Tile::TONG(10).svg_markup(SvgStyle::DARK), None);
+        assert_eq!(Tile::TONG(10).svg_data(SvgStyle::DARK), None);
+    }
+
+    #[test]
+    fn by_id() {
+        for style in SvgStyle::ALL {
+            for tile in (0..Tile::KINDS).filter_map(Tile::from_id) {
+                assert_eq!(
+                    style.get_svg_by_id(tile.id()),
+                    style.get_svg_by_name(tile.svg_name().unwrap())
+                );
+                assert_eq!(
+                    style.get_svg_markup_by_id(tile.id()),
+                    style.get_svg_markup_by_name(tile.svg_name().unwrap())
+                );
+            }
+            assert_eq!(style.get_svg_by_id(Tile::KINDS), None);
+            assert_eq!(style.get_svg_markup_by_id(Tile::KINDS), None);
+        }
+    }
+
+    #[test]
+    fn style_names() {
+        assert_eq!(SvgStyle::DARK.name(), "dark");
+        assert_eq!(SvgStyle::from_name("light"), Some(SvgStyle::LIGHT));
+        assert_eq!(SvgStyle::from_name("sepia"), None);
+        assert!(SvgStyle::ALL
+            .iter()
+            .all(|style| SvgStyle::from_name(style.name()) == Some(*style)));
     }
 }
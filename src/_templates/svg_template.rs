@@ -1,8 +1,47 @@
 //! Build time generated from `{{SOURCE_PATH}}`.
 //!
 
+use std::sync::OnceLock;
+
 {{DECLARATIONS}}
 
+/// The embedded SVGs above, in order.
+const SOURCES: [{{SOURCE_TYPE}}; {{COUNT}}] = [
+{{SOURCES}}
+];
+
+/// The index into [`SOURCES`] of the SVG for each tile, indexed by
+/// [`Tile::id`](crate::Tile::id).
+const TILE_INDICES: [usize; {{TILE_COUNT}}] = [
+{{TILE_INDICES}}
+];
+
+/// Base64 data URIs of the SVG markup, encoded on first use.
+static DATA_URIS: [OnceLock<String>; {{COUNT}}] = [const { OnceLock::new() }; {{COUNT}}];
+{{MARKUP_CACHE}}
+/// Get the raw SVG markup at `index` in [`SOURCES`].
+fn markup(index: usize) -> &'static str {
+    {{MARKUP}}
+}
+
+/// Get the SVG data at `index` in [`SOURCES`].
+fn data_uri(index: usize) -> &'static str {
+    super::cached_data_uri(&DATA_URIS[index], markup(index))
+}
+
+/// Get the raw SVG markup for the given name.
+///
+/// # Returns
+///
+/// - `Some(&'static str)`: The SVG markup for the given name.
+/// - `None`: If the name is not found.
+pub fn get_svg_markup_by_name(name: &str) -> Option<&'static str> {
+    match name {
+{{MARKUP_MATCHES}}
+        _ => None,
+    }
+}
+
 /// Get the SVG data for the given name.
 ///
 /// # Returns
@@ -11,7 +50,27 @@
 /// - `None`: If the name is not found.
 pub fn get_svg_by_name(name: &str) -> Option<&'static str> {
     match name {
-{{MATCHES}}
+{{DATA_URI_MATCHES}}
         _ => None,
     }
 }
+
+/// Get the raw SVG markup for the tile with the given [`Tile::id`](crate::Tile::id).
+///
+/// # Returns
+///
+/// - `Some(&'static str)`: The SVG markup for the tile.
+/// - `None`: If the ID is not a valid tile ID.
+pub fn get_svg_markup_by_id(id: u8) -> Option<&'static str> {
+    TILE_INDICES.get(usize::from(id)).map(|&index| markup(index))
+}
+
+/// Get the SVG data for the tile with the given [`Tile::id`](crate::Tile::id).
+///
+/// # Returns
+///
+/// - `Some(&'static str)`: The SVG data for the tile.
+/// - `None`: If the ID is not a valid tile ID.
+pub fn get_svg_by_id(id: u8) -> Option<&'static str> {
+    TILE_INDICES.get(usize::from(id)).map(|&index| data_uri(index))
+}
@@ -0,0 +1,13 @@
+//! Serves the embedded tile images over HTTP on the address given, or on
+//! `127.0.0.1:8080`.
+
+use mahjong_tiles::server::TileServer;
+
+fn main() -> std::io::Result<()> {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:8080".to_string());
+
+    eprintln!("Serving tile images on http://{address}/");
+    TileServer::default().serve(address.as_str())
+}
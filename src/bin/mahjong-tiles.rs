@@ -0,0 +1,318 @@
+//! Command-line tool for checking, analysing and rendering hands in mpsz notation.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use mahjong_tiles::hand::{self, format_hand, parse_hand, shanten, waits, Win};
+use mahjong_tiles::render::{render_hand, render_hand_text, Layout, TextStyle};
+use mahjong_tiles::{IsTile, SvgStyle, Tile};
+
+/// Analyse and render Mahjong hands written in mpsz notation, e.g. `123m456p789s1122z`.
+#[derive(Debug, Parser)]
+#[command(name = "mahjong-tiles", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check a hand and print its tiles, sorted, as notation, characters and names.
+    Parse {
+        /// The hand in mpsz notation.
+        hand: String,
+    },
+    /// Print the shanten of a hand: 0 if ready, -1 if complete.
+    Shanten {
+        /// The concealed tiles in mpsz notation.
+        hand: String,
+    },
+    /// Print the tiles that would complete a ready hand.
+    Waits {
+        /// The concealed tiles in mpsz notation, without the winning tile.
+        hand: String,
+    },
+    /// Score a concealed winning hand, won on the last tile written.
+    Score {
+        /// The complete hand in mpsz notation, including the winning tile.
+        hand: String,
+        /// The rules to score by.
+        #[arg(long, value_enum)]
+        rules: Rules,
+        /// The winning tile, if it is not the last tile written.
+        #[arg(long)]
+        win: Option<String>,
+        /// Whether the winning tile was drawn from the wall rather than claimed.
+        #[arg(long)]
+        self_drawn: bool,
+        /// The wind of the winner's seat.
+        #[arg(long, default_value = "1z")]
+        seat_wind: String,
+        /// The prevailing wind.
+        #[arg(long, default_value = "1z")]
+        round_wind: String,
+    },
+    /// Render a hand to an SVG image.
+    Render {
+        /// The hand in mpsz notation, drawn in the order given.
+        hand: String,
+        /// The name of the tile style, e.g. `light`.
+        #[arg(long)]
+        style: Option<String>,
+        /// The file to write to, instead of standard output.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// Rule sets for scoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Rules {
+    /// Hong Kong rules.
+    Hk,
+    /// Japanese riichi rules.
+    Riichi,
+    /// Mahjong Competition Rules.
+    Mcr,
+}
+
+impl From<Rules> for hand::Rules {
+    fn from(rules: Rules) -> Self {
+        match rules {
+            Rules::Hk => hand::Rules::HK,
+            Rules::Riichi => hand::Rules::RIICHI,
+            Rules::Mcr => hand::Rules::MCR,
+        }
+    }
+}
+
+/// Parse a hand, describing any error for the user.
+fn hand(notation: &str) -> Result<Vec<Tile>, String> {
+    parse_hand(notation).map_err(|error| format!("invalid hand {notation:?}: {error}"))
+}
+
+/// Parse a single tile, describing any error for the user.
+fn tile(notation: &str) -> Result<Tile, String> {
+    match hand(notation)?.as_slice() {
+        [tile] => Ok(*tile),
+        _ => Err(format!("expected a single tile, not {notation:?}")),
+    }
+}
+
+/// Look up a style by name, or the first embedded style if none is given.
+fn style(name: Option<&str>) -> Result<SvgStyle, String> {
+    let names = || {
+        SvgStyle::ALL
+            .iter()
+            .map(|style| style.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match name {
+        Some(name) => SvgStyle::from_name(name)
+            .ok_or_else(|| format!("unknown style {name:?}; expected one of: {}", names())),
+        None => SvgStyle::ALL
+            .first()
+            .copied()
+            .ok_or_else(|| "no styles are embedded in this build".to_string()),
+    }
+}
+
+/// Run a command, returning what to print on success.
+fn run(command: Command) -> Result<String, String> {
+    match command {
+        Command::Parse { hand: notation } => {
+            let mut tiles = hand(&notation)?;
+            tiles.sort_by_key(Tile::id);
+            Ok(format!(
+                "{} ({} tiles)\n{}\n{}",
+                format_hand(&tiles).map_err(|error| error.to_string())?,
+                tiles.len(),
+                render_hand_text(&tiles, &[], TextStyle::UNICODE, false).trim_end(),
+                tiles
+                    .iter()
+                    .filter_map(IsTile::unique_name)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ))
+        }
+        Command::Shanten { hand: notation } => shanten(&hand(&notation)?)
+            .map(|shanten| shanten.to_string())
+            .map_err(|error| error.to_string()),
+        Command::Waits { hand: notation } => {
+            let waits = waits(&hand(&notation)?).map_err(|error| error.to_string())?;
+            if waits.is_empty() {
+                Ok("not ready".to_string())
+            } else {
+                format_hand(&waits).map_err(|error| error.to_string())
+            }
+        }
+        Command::Score {
+            hand: notation,
+            rules,
+            win,
+            self_drawn,
+            seat_wind,
+            round_wind,
+        } => {
+            let tiles = hand(&notation)?;
+            let win = Win {
+                tile: match win {
+                    Some(win) => tile(&win)?,
+                    None => *tiles
+                        .iter()
+                        .rfind(|tile| !tile.is_flower())
+                        .ok_or_else(|| format!("hand {notation:?} has no winning tile"))?,
+                },
+                self_drawn,
+                seat_wind: tile(&seat_wind)?,
+                round_wind: tile(&round_wind)?,
+            };
+            let rules = hand::Rules::from(rules);
+            let score = hand::score(&tiles, &[], &win, rules).map_err(|error| error.to_string())?;
+            if score.value == 0 {
+                return Err("the hand has no yaku, so it cannot win".to_string());
+            }
+
+            let total = match (score.fu, score.points) {
+                (Some(fu), Some(points)) => {
+                    format!("{} {} {fu} fu, {points} points", score.value, rules.unit())
+                }
+                (None, Some(points)) => {
+                    format!("{} {}, {points} points", score.value, rules.unit())
+                }
+                _ => format!("{} {}", score.value, rules.unit()),
+            };
+            Ok(score
+                .patterns
+                .iter()
+                .map(|pattern| format!("{} {}", pattern.name, pattern.value))
+                .chain([total])
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        Command::Render {
+            hand: notation,
+            style: name,
+            output,
+        } => {
+            let svg = render_hand(
+                &hand(&notation)?,
+                &[],
+                style(name.as_deref())?,
+                Layout::default(),
+            );
+            match output {
+                Some(path) => std::fs::write(&path, svg)
+                    .map(|_| format!("wrote {}", path.display()))
+                    .map_err(|error| format!("could not write {}: {error}", path.display())),
+                None => Ok(svg),
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(all(test, feature = "theme-dark"))]
+mod test {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> Result<String, String> {
+        let cli = Cli::try_parse_from(std::iter::once("mahjong-tiles").chain(args.iter().copied()))
+            .map_err(|error| error.to_string())?;
+        run(cli.command)
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            run_args(&["parse", "5z 1m 23m"]),
+            Ok("123m5z (4 tiles)\n🀇 🀈 🀉 🀆\n一万 二万 三万 白".to_string())
+        );
+        assert!(run_args(&["parse", "8z"]).unwrap_err().contains("8z"));
+    }
+
+    #[test]
+    fn analysis() {
+        assert_eq!(
+            run_args(&["shanten", "123m456p789s1122z"]),
+            Ok("0".to_string())
+        );
+        assert_eq!(
+            run_args(&["waits", "23m456p789s11155z"]),
+            Ok("14m".to_string())
+        );
+        assert_eq!(
+            run_args(&["waits", "147m258p369s1234z"]),
+            Ok("not ready".to_string())
+        );
+        assert!(run_args(&["waits", "123m456p789s11222z"]).is_err());
+    }
+
+    #[test]
+    fn score() {
+        assert_eq!(
+            run_args(&[
+                "score",
+                "--rules",
+                "riichi",
+                "--seat-wind",
+                "2z",
+                "234m456p678s88p234s"
+            ]),
+            Ok("Pinfu 1\nAll simples 1\n2 han 30 fu, 2000 points".to_string())
+        );
+        assert_eq!(
+            run_args(&[
+                "score",
+                "--rules",
+                "hk",
+                "--self-drawn",
+                "123m456m789m234m99m"
+            ]),
+            Ok("Self-drawn 1\nConcealed hand 1\nAll chows 1\nPure one suit 7\n10 faan".to_string())
+        );
+        assert_eq!(
+            run_args(&["score", "--rules", "mcr", "--win", "2z", "111z222z333z444z55z"]),
+            Ok(
+                "Big Four Winds 88\nAll Honours 64\nThree Concealed Pungs 16\nConcealed Hand 2\n170 fan"
+                    .to_string()
+            )
+        );
+        assert!(run_args(&[
+            "score",
+            "--rules",
+            "riichi",
+            "--seat-wind",
+            "2z",
+            "123m456p789s234s55p"
+        ])
+        .unwrap_err()
+        .contains("no yaku"));
+        assert!(run_args(&["score", "--rules", "riichi", "123m456p789s11z22z"]).is_err());
+        assert!(run_args(&["score", "--rules", "zung-jung", "123m"]).is_err());
+    }
+
+    #[test]
+    fn render() {
+        let svg = run_args(&["render", "123m", "--style", "dark"]).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<image").count(), 6);
+        assert!(run_args(&["render", "123m", "--style", "sepia"])
+            .unwrap_err()
+            .contains("dark"));
+    }
+}
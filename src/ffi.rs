@@ -0,0 +1,493 @@
+//! A C API for linking the static or dynamic library from other languages, declared
+//! in `include/mahjong_tiles.h`. Build it with `cargo rustc --release --lib --features ffi
+//! --crate-type staticlib`, or `cdylib` for a shared library.
+//!
+//! The build generates the header into `OUT_DIR` with cbindgen, and the tests check
+//! that the committed copy matches it. Comments here are copied into the header, so
+//! they do not use intra-doc links.
+//!
+//! Tiles are passed as their [`Tile::id`], and styles by their [`SvgStyle::name`],
+//! as NUL-terminated UTF-8. Functions that fill a buffer take its capacity and write
+//! the length needed through `len`, even if the buffer is too small, so that the
+//! caller can retry with a larger one.
+//!
+//! ```c
+//! uint8_t hand[14];
+//! size_t len;
+//! int8_t shanten;
+//! if (mahjong_parse_hand("23m456p789s11155z", hand, sizeof hand, &len) == MAHJONG_STATUS_OK &&
+//!     mahjong_shanten(hand, len, &shanten) == MAHJONG_STATUS_OK) {
+//!     printf("%d\n", shanten);  // 0
+//! }
+//! ```
+
+use std::ffi::{c_char, CStr};
+
+use crate::hand::{self, HandError};
+use crate::models::Tile;
+use crate::svg::{HasSvgData, SvgStyle};
+
+/// Returned in place of a tile identifier that is not valid.
+pub const MAHJONG_INVALID_TILE: u8 = 0xFF;
+
+/// The result of a call to the C API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MahjongStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A pointer is null, or a string is not UTF-8.
+    InvalidArgument = -1,
+    /// A tile identifier is out of range.
+    InvalidTile = -2,
+    /// Hand notation could not be parsed.
+    InvalidNotation = -3,
+    /// The hand has a number of tiles that cannot be analysed.
+    InvalidSize = -4,
+    /// The hand holds more than four copies of a tile.
+    TooManyCopies = -5,
+    /// The output buffer is too small; the length needed has been written.
+    BufferTooSmall = -6,
+}
+
+impl From<HandError> for MahjongStatus {
+    fn from(error: HandError) -> Self {
+        match error {
+            HandError::MissingSuit
+            | HandError::InvalidCharacter(_)
+            | HandError::InvalidValue { .. } => Self::InvalidNotation,
+            HandError::InvalidSize(_) => Self::InvalidSize,
+            HandError::TooManyCopies(_) => Self::TooManyCopies,
+            HandError::InvalidTile(_) => Self::InvalidTile,
+            HandError::InvalidWind(_)
+            | HandError::WinningTileNotInHand(_)
+            | HandError::NotComplete => Self::InvalidArgument,
+        }
+    }
+}
+
+/// Unwrap a result, or return its error status.
+macro_rules! try_status {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(status) => return MahjongStatus::from(status),
+        }
+    };
+}
+
+/// Read a NUL-terminated UTF-8 string.
+///
+/// # Safety
+///
+/// `string` must be null or point to a NUL-terminated string.
+unsafe fn read_str<'a>(string: *const c_char) -> Result<&'a str, MahjongStatus> {
+    if string.is_null() {
+        return Err(MahjongStatus::InvalidArgument);
+    }
+    CStr::from_ptr(string)
+        .to_str()
+        .map_err(|_| MahjongStatus::InvalidArgument)
+}
+
+/// Read an array of tile identifiers.
+///
+/// # Safety
+///
+/// `tiles` must be null or point to `count` bytes.
+unsafe fn read_tiles(tiles: *const u8, count: usize) -> Result<Vec<Tile>, MahjongStatus> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    if tiles.is_null() {
+        return Err(MahjongStatus::InvalidArgument);
+    }
+    std::slice::from_raw_parts(tiles, count)
+        .iter()
+        .map(|id| Tile::from_id(*id).ok_or(MahjongStatus::InvalidTile))
+        .collect()
+}
+
+/// Copy bytes into a buffer of `capacity`, writing their length to `len`, and a NUL
+/// terminator after them if `terminate` is set. When there is nothing to write, the
+/// buffer may be null.
+///
+/// # Safety
+///
+/// `buffer` must be null or point to `capacity` writable bytes, and `len` must be
+/// null or writable.
+unsafe fn write_bytes(
+    bytes: &[u8],
+    terminate: bool,
+    buffer: *mut u8,
+    capacity: usize,
+    len: *mut usize,
+) -> MahjongStatus {
+    if len.is_null() {
+        return MahjongStatus::InvalidArgument;
+    }
+    *len = bytes.len();
+    if bytes.len() + usize::from(terminate) > capacity {
+        return MahjongStatus::BufferTooSmall;
+    }
+    if bytes.is_empty() && !terminate {
+        return MahjongStatus::Ok;
+    }
+    if buffer.is_null() {
+        return MahjongStatus::InvalidArgument;
+    }
+
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer, bytes.len());
+    if terminate {
+        *buffer.add(bytes.len()) = 0;
+    }
+    MahjongStatus::Ok
+}
+
+/// Write the static SVG data, or null, returning a pointer to it.
+///
+/// # Safety
+///
+/// `len` must be null or writable.
+unsafe fn write_svg(data: Option<&'static str>, len: *mut usize) -> *const c_char {
+    match data {
+        Some(data) if !len.is_null() => {
+            *len = data.len();
+            data.as_ptr().cast()
+        }
+        _ => std::ptr::null(),
+    }
+}
+
+/// Returns the identifier of the tile with the given name in Chinese or Japanese, or
+/// in mpsz notation, or `MAHJONG_INVALID_TILE`.
+///
+/// # Safety
+///
+/// `name` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_tile_from_name(name: *const c_char) -> u8 {
+    read_str(name)
+        .ok()
+        .and_then(|name| Tile::from_name(name).ok())
+        .map_or(MAHJONG_INVALID_TILE, |tile| tile.id())
+}
+
+/// Returns the number on a tile, or 0 for honours, flowers and invalid identifiers.
+#[no_mangle]
+pub extern "C" fn mahjong_tile_value(tile: u8) -> u8 {
+    Tile::from_id(tile)
+        .and_then(|tile| tile.value())
+        .unwrap_or(0)
+}
+
+/// Returns whether a tile is a wind or a dragon.
+#[no_mangle]
+pub extern "C" fn mahjong_tile_is_honour(tile: u8) -> bool {
+    Tile::from_id(tile).is_some_and(|tile| tile.is_honour())
+}
+
+/// Returns whether a tile is a flower or a season.
+#[no_mangle]
+pub extern "C" fn mahjong_tile_is_flower(tile: u8) -> bool {
+    Tile::from_id(tile).is_some_and(|tile| tile.is_flower())
+}
+
+/// Writes the SVG name of a tile, e.g. `tong-5`, NUL-terminated.
+///
+/// # Safety
+///
+/// `buffer` must point to `capacity` writable bytes, and `len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_tile_svg_name(
+    tile: u8,
+    buffer: *mut c_char,
+    capacity: usize,
+    len: *mut usize,
+) -> MahjongStatus {
+    let tile = try_status!(Tile::from_id(tile).ok_or(MahjongStatus::InvalidTile));
+    write_bytes(
+        tile.svg_name()
+            .expect("Tiles from identifiers should have SVG names.")
+            .as_bytes(),
+        true,
+        buffer.cast(),
+        capacity,
+        len,
+    )
+}
+
+/// Returns the SVG data URI for a tile or base layer by its SVG name, writing its
+/// length to `len`, or null if the style or name is unknown.
+///
+/// The data is static and is not NUL-terminated.
+///
+/// # Safety
+///
+/// `style` and `name` must be null or point to NUL-terminated strings, and `len` must
+/// be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_svg_data(
+    style: *const c_char,
+    name: *const c_char,
+    len: *mut usize,
+) -> *const c_char {
+    let data = read_str(style)
+        .ok()
+        .and_then(SvgStyle::from_name)
+        .zip(read_str(name).ok())
+        .and_then(|(style, name)| style.get_svg_by_name(name));
+    write_svg(data, len)
+}
+
+/// Returns the SVG data URI for a tile, as `mahjong_svg_data`.
+///
+/// # Safety
+///
+/// `style` must be null or point to a NUL-terminated string, and `len` must be null
+/// or writable.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_tile_svg_data(
+    tile: u8,
+    style: *const c_char,
+    len: *mut usize,
+) -> *const c_char {
+    let data = read_str(style)
+        .ok()
+        .and_then(SvgStyle::from_name)
+        .zip(Tile::from_id(tile))
+        .and_then(|(style, tile)| tile.svg_data(style));
+    write_svg(data, len)
+}
+
+/// Parses a hand in mpsz notation into tile identifiers.
+///
+/// # Safety
+///
+/// `notation` must point to a NUL-terminated string, `tiles` to `capacity` writable
+/// bytes, and `len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_parse_hand(
+    notation: *const c_char,
+    tiles: *mut u8,
+    capacity: usize,
+    len: *mut usize,
+) -> MahjongStatus {
+    let hand = try_status!(hand::parse_hand(try_status!(read_str(notation))));
+    let ids = hand.iter().map(Tile::id).collect::<Vec<_>>();
+    write_bytes(&ids, false, tiles, capacity, len)
+}
+
+/// Formats tile identifiers as a hand in mpsz notation, NUL-terminated.
+///
+/// # Safety
+///
+/// `tiles` must point to `count` bytes, `buffer` to `capacity` writable bytes, and
+/// `len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_format_hand(
+    tiles: *const u8,
+    count: usize,
+    buffer: *mut c_char,
+    capacity: usize,
+    len: *mut usize,
+) -> MahjongStatus {
+    let notation = try_status!(hand::format_hand(&try_status!(read_tiles(tiles, count))));
+    write_bytes(notation.as_bytes(), true, buffer.cast(), capacity, len)
+}
+
+/// Writes the shanten of a hand: 0 if it is ready, and -1 if it is complete.
+///
+/// # Safety
+///
+/// `tiles` must point to `count` bytes, and `shanten` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_shanten(
+    tiles: *const u8,
+    count: usize,
+    shanten: *mut i8,
+) -> MahjongStatus {
+    if shanten.is_null() {
+        return MahjongStatus::InvalidArgument;
+    }
+    *shanten = try_status!(hand::shanten(&try_status!(read_tiles(tiles, count))));
+    MahjongStatus::Ok
+}
+
+/// Writes the identifiers of the tiles that would complete a ready hand.
+///
+/// # Safety
+///
+/// `tiles` must point to `count` bytes, `waits` to `capacity` writable bytes, and
+/// `len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn mahjong_waits(
+    tiles: *const u8,
+    count: usize,
+    waits: *mut u8,
+    capacity: usize,
+    len: *mut usize,
+) -> MahjongStatus {
+    let hand = try_status!(hand::waits(&try_status!(read_tiles(tiles, count))));
+    let ids = hand.iter().map(Tile::id).collect::<Vec<_>>();
+    write_bytes(&ids, false, waits, capacity, len)
+}
+
+#[cfg(all(test, feature = "theme-light"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn header_is_current() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/mahjong_tiles.h"));
+
+        assert!(
+            generated == include_str!("../include/mahjong_tiles.h"),
+            "include/mahjong_tiles.h should match the generated {}/mahjong_tiles.h",
+            env!("OUT_DIR")
+        );
+        assert!(
+            !generated.contains("[`"),
+            "The header should not contain intra-doc links."
+        );
+    }
+
+    #[test]
+    fn tiles() {
+        unsafe {
+            assert_eq!(mahjong_tile_from_name(c"五筒".as_ptr()), 13);
+            assert_eq!(mahjong_tile_from_name(c"5x".as_ptr()), MAHJONG_INVALID_TILE);
+            assert_eq!(
+                mahjong_tile_from_name(std::ptr::null()),
+                MAHJONG_INVALID_TILE
+            );
+        }
+        assert_eq!(mahjong_tile_value(13), 5);
+        assert_eq!(mahjong_tile_value(27), 0);
+        assert!(mahjong_tile_is_honour(27));
+        assert!(mahjong_tile_is_flower(34));
+        assert!(!mahjong_tile_is_flower(42));
+    }
+
+    #[test]
+    fn svg_name() {
+        let mut buffer = [0u8; 8];
+        let mut len = 0;
+        unsafe {
+            assert_eq!(
+                mahjong_tile_svg_name(13, buffer.as_mut_ptr().cast(), 8, &mut len),
+                MahjongStatus::Ok
+            );
+            assert_eq!(&buffer[..=len], b"tong-5\0");
+            assert_eq!(
+                mahjong_tile_svg_name(27, buffer.as_mut_ptr().cast(), 8, &mut len),
+                MahjongStatus::BufferTooSmall
+            );
+            assert_eq!(len, "honour-east".len());
+        }
+    }
+
+    #[test]
+    fn svg_data() {
+        let mut len = 0;
+        unsafe {
+            let data = mahjong_tile_svg_data(27, c"light".as_ptr(), &mut len);
+            let data = std::slice::from_raw_parts(data.cast::<u8>(), len);
+            assert_eq!(
+                Some(std::str::from_utf8(data).unwrap()),
+                Tile::EAST.svg_data(SvgStyle::LIGHT)
+            );
+            assert_eq!(
+                mahjong_svg_data(c"light".as_ptr(), c"honour-east".as_ptr(), &mut len).cast::<u8>(),
+                data.as_ptr()
+            );
+            assert!(mahjong_svg_data(c"sepia".as_ptr(), c"base-back".as_ptr(), &mut len).is_null());
+            assert!(mahjong_tile_svg_data(42, c"light".as_ptr(), &mut len).is_null());
+        }
+    }
+
+    #[test]
+    fn hand() {
+        let mut tiles = [0u8; 14];
+        let mut buffer = [0u8; 32];
+        let mut len = 0;
+        let mut shanten = 0;
+        unsafe {
+            assert_eq!(
+                mahjong_parse_hand(
+                    c"23m456p789s11155z".as_ptr(),
+                    tiles.as_mut_ptr(),
+                    14,
+                    &mut len
+                ),
+                MahjongStatus::Ok
+            );
+            let count = len;
+            assert_eq!(count, 13);
+            assert_eq!(
+                mahjong_format_hand(
+                    tiles.as_ptr(),
+                    count,
+                    buffer.as_mut_ptr().cast(),
+                    32,
+                    &mut len
+                ),
+                MahjongStatus::Ok
+            );
+            assert_eq!(&buffer[..=len], b"23m456p789s11155z\0");
+            assert_eq!(
+                mahjong_shanten(tiles.as_ptr(), count, &mut shanten),
+                MahjongStatus::Ok
+            );
+            assert_eq!(shanten, 0);
+
+            let mut waits = [0u8; 1];
+            assert_eq!(
+                mahjong_waits(tiles.as_ptr(), count, waits.as_mut_ptr(), 1, &mut len),
+                MahjongStatus::BufferTooSmall
+            );
+            assert_eq!(len, 2);
+        }
+    }
+
+    #[test]
+    fn empty_result() {
+        let mut len = 1;
+        unsafe {
+            assert_eq!(
+                mahjong_parse_hand(c"".as_ptr(), std::ptr::null_mut(), 0, &mut len),
+                MahjongStatus::Ok
+            );
+            assert_eq!(len, 0);
+            assert_eq!(
+                mahjong_parse_hand(c"1m".as_ptr(), std::ptr::null_mut(), 1, &mut len),
+                MahjongStatus::InvalidArgument
+            );
+        }
+    }
+
+    #[test]
+    fn hand_errors() {
+        let mut tiles = [0u8; 14];
+        let mut len = 0;
+        let mut shanten = 0;
+        unsafe {
+            assert_eq!(
+                mahjong_parse_hand(c"8z".as_ptr(), tiles.as_mut_ptr(), 14, &mut len),
+                MahjongStatus::InvalidNotation
+            );
+            assert_eq!(
+                mahjong_shanten([42].as_ptr(), 1, &mut shanten),
+                MahjongStatus::InvalidTile
+            );
+            assert_eq!(
+                mahjong_shanten([0, 1, 2].as_ptr(), 3, &mut shanten),
+                MahjongStatus::InvalidSize
+            );
+            assert_eq!(
+                mahjong_shanten(std::ptr::null(), 1, &mut shanten),
+                MahjongStatus::InvalidArgument
+            );
+        }
+    }
+}
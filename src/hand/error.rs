@@ -0,0 +1,54 @@
+//! Errors raised while parsing or analysing hands.
+
+use crate::models::Tile;
+
+/// Errors raised while parsing or analysing a hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandError {
+    /// The notation has digits with no suit letter after them.
+    MissingSuit,
+    /// A character in the notation is neither a digit nor a suit letter.
+    InvalidCharacter(char),
+    /// A digit is out of range for its suit, e.g. `8z`.
+    InvalidValue {
+        /// The digit.
+        value: char,
+        /// The suit letter.
+        suit: char,
+    },
+    /// The hand has a number of tiles that cannot be analysed; holds the number of
+    /// tiles, not counting flowers and seasons.
+    InvalidSize(usize),
+    /// The hand holds more than four copies of a tile.
+    TooManyCopies(Tile),
+    /// The hand holds a number tile with a value outside `1..=9`.
+    InvalidTile(Tile),
+    /// A seat or prevailing wind is not a wind.
+    InvalidWind(Tile),
+    /// The winning tile is not one of the concealed tiles of the hand.
+    WinningTileNotInHand(Tile),
+    /// The hand is not a winning hand under the rules it is scored by.
+    NotComplete,
+}
+
+impl core::fmt::Display for HandError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingSuit => write!(f, "Hand notation ends without a suit letter."),
+            Self::InvalidCharacter(c) => write!(f, "{c:?} is not valid in hand notation."),
+            Self::InvalidValue { value, suit } => {
+                write!(f, "{value}{suit} is not a valid tile.")
+            }
+            Self::InvalidSize(size) => write!(f, "A hand of {size} tiles cannot be analysed."),
+            Self::TooManyCopies(tile) => write!(f, "Hand holds more than four {tile:?}."),
+            Self::InvalidTile(tile) => write!(f, "{tile:?} is not a valid tile."),
+            Self::InvalidWind(tile) => write!(f, "{tile:?} is not a wind."),
+            Self::WinningTileNotInHand(tile) => {
+                write!(f, "Winning tile {tile:?} is not in the hand.")
+            }
+            Self::NotComplete => write!(f, "Hand is not a winning hand."),
+        }
+    }
+}
+
+impl core::error::Error for HandError {}
@@ -0,0 +1,26 @@
+//! Parsing, formatting, analysis and scoring of hands.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mahjong_tiles::Tile;
+//! use mahjong_tiles::hand::{format_hand, parse_hand, shanten, waits};
+//!
+//! let hand = parse_hand("23m 456p 789s 111z 55z").unwrap();
+//!
+//! assert_eq!(format_hand(&hand).as_deref(), Ok("23m456p789s11155z"));
+//! assert_eq!(shanten(&hand), Ok(0));
+//! assert_eq!(waits(&hand), Ok(vec![Tile::WAN(1), Tile::WAN(4)]));
+//! ```
+
+mod error;
+pub use error::*;
+
+mod notation;
+pub use notation::*;
+
+mod score;
+pub use score::*;
+
+mod shanten;
+pub use shanten::*;
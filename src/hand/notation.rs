@@ -0,0 +1,204 @@
+//! The compact `mpsz` notation for hands, as used by Tenhou and most riichi tools.
+
+use alloc::{string::String, vec::Vec};
+
+use super::HandError;
+use crate::models::Tile;
+
+/// Get the tile for a digit in the given suit.
+fn tile(value: char, suit: char) -> Result<Tile, HandError> {
+    let invalid = HandError::InvalidValue { value, suit };
+    let digit = value.to_digit(10).ok_or(invalid.clone())? as u8;
+
+    // Red fives are written as `0`, but are the same kind as any other five.
+    let number = if digit == 0 { 5 } else { digit };
+    match (suit, digit) {
+        ('m', _) => Ok(Tile::WAN(number)),
+        ('p', _) => Ok(Tile::TONG(number)),
+        ('s', _) => Ok(Tile::TIAO(number)),
+        ('z', 1..=7) => Ok([
+            Tile::EAST,
+            Tile::SOUTH,
+            Tile::WEST,
+            Tile::NORTH,
+            Tile::BLANK,
+            Tile::PROSPERITY,
+            Tile::CENTRAL,
+        ][usize::from(digit) - 1]),
+        ('f', 1..=8) => Ok([
+            Tile::PLUM,
+            Tile::ORCHID,
+            Tile::CHRYSANTHEMUM,
+            Tile::BAMBOO,
+            Tile::SPRING,
+            Tile::SUMMER,
+            Tile::AUTUMN,
+            Tile::WINTER,
+        ][usize::from(digit) - 1]),
+        _ => Err(invalid),
+    }
+}
+
+/// Get the suit letter and digit for a tile; the reverse of [`tile`].
+fn notation(tile: Tile) -> (char, u8) {
+    match tile {
+        Tile::WAN(v) => ('m', v),
+        Tile::TONG(v) => ('p', v),
+        Tile::TIAO(v) => ('s', v),
+        _ if tile.id() < 34 => ('z', tile.id() - 27 + 1),
+        _ => ('f', tile.id() - 34 + 1),
+    }
+}
+
+/// Parse a hand from `mpsz` notation, e.g. `123m456p789s11z`.
+///
+/// Each run of digits takes the suit letter that follows it: `m` for Wan, `p` for
+/// Tong, `s` for Tiao and `z` for the honours, `1` to `7` being East, South, West,
+/// North, Blank, Prosperity and Central. `0` is a red five. As an extension, `f`
+/// takes the flowers as `1` to `4` and the seasons as `5` to `8`. Whitespace is
+/// ignored, and tiles are returned in the order written.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::Tile;
+/// use mahjong_tiles::hand::parse_hand;
+///
+/// assert_eq!(
+///     parse_hand("19m 05p 7z").unwrap(),
+///     vec![Tile::WAN(1), Tile::WAN(9), Tile::TONG(5), Tile::TONG(5), Tile::CENTRAL]
+/// );
+/// ```
+pub fn parse_hand(notation: &str) -> Result<Vec<Tile>, HandError> {
+    let mut tiles = Vec::new();
+    let mut pending = Vec::new();
+
+    for c in notation.chars().filter(|c| !c.is_whitespace()) {
+        match c {
+            '0'..='9' => pending.push(c),
+            'm' | 'p' | 's' | 'z' | 'f' => {
+                if pending.is_empty() {
+                    return Err(HandError::InvalidCharacter(c));
+                }
+                for value in pending.drain(..) {
+                    tiles.push(tile(value, c)?);
+                }
+            }
+            _ => return Err(HandError::InvalidCharacter(c)),
+        }
+    }
+
+    if !pending.is_empty() {
+        return Err(HandError::MissingSuit);
+    }
+    Ok(tiles)
+}
+
+/// Format a hand in `mpsz` notation; see [`parse_hand`].
+///
+/// Tiles are grouped by suit in the order `m`, `p`, `s`, `z`, `f`, and sorted
+/// within each suit. Fails with [`HandError::InvalidTile`] if a tile is not valid;
+/// see [`Tile::checked_id`].
+pub fn format_hand(tiles: &[Tile]) -> Result<String, HandError> {
+    if let Some(tile) = tiles.iter().find(|tile| tile.checked_id().is_none()) {
+        return Err(HandError::InvalidTile(*tile));
+    }
+
+    let mut tiles = tiles.to_vec();
+    tiles.sort();
+
+    let mut output = String::new();
+    let mut suit = None;
+    for tile in tiles {
+        let (next, digit) = notation(tile);
+        if let Some(previous) = suit.filter(|suit| *suit != next) {
+            output.push(previous);
+        }
+        suit = Some(next);
+        output.push(char::from(b'0' + digit));
+    }
+    if let Some(suit) = suit {
+        output.push(suit);
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $input:literal, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(parse_hand($input), $expected);
+            }
+        };
+    }
+
+    create_test!(empty, "", Ok(vec![]));
+    create_test!(
+        suits,
+        "1m2p3s4z",
+        Ok(vec![
+            Tile::WAN(1),
+            Tile::TONG(2),
+            Tile::TIAO(3),
+            Tile::NORTH
+        ])
+    );
+    create_test!(
+        dragons,
+        "567z",
+        Ok(vec![Tile::BLANK, Tile::PROSPERITY, Tile::CENTRAL])
+    );
+    create_test!(red_five, "0s", Ok(vec![Tile::TIAO(5)]));
+    create_test!(flowers, "18f", Ok(vec![Tile::PLUM, Tile::WINTER]));
+    create_test!(missing_suit, "123m45", Err(HandError::MissingSuit));
+    create_test!(lone_suit, "m", Err(HandError::InvalidCharacter('m')));
+    create_test!(invalid, "12x", Err(HandError::InvalidCharacter('x')));
+    create_test!(
+        invalid_honour,
+        "8z",
+        Err(HandError::InvalidValue {
+            value: '8',
+            suit: 'z'
+        })
+    );
+    create_test!(
+        invalid_flower,
+        "0f",
+        Err(HandError::InvalidValue {
+            value: '0',
+            suit: 'f'
+        })
+    );
+
+    #[test]
+    fn format() {
+        let tiles = parse_hand("7z 987s 1p 3f 11m").unwrap();
+
+        assert_eq!(format_hand(&tiles).unwrap(), "11m1p789s7z3f");
+        assert_eq!(format_hand(&[]).unwrap(), "");
+    }
+
+    #[test]
+    fn round_trip() {
+        let tiles = (0..Tile::KINDS)
+            .filter_map(Tile::from_id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(parse_hand(&format_hand(&tiles).unwrap()).unwrap(), tiles);
+    }
+
+    #[test]
+    fn invalid_tile() {
+        for tile in [Tile::TONG(10), Tile::WAN(0), Tile::TIAO(u8::MAX)] {
+            assert_eq!(
+                format_hand(&[Tile::TONG(1), tile]),
+                Err(HandError::InvalidTile(tile))
+            );
+        }
+    }
+}
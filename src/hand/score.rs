@@ -0,0 +1,1357 @@
+//! Scoring of winning hands under Hong Kong, riichi and Mahjong Competition Rules.
+
+use alloc::{vec, vec::Vec};
+
+use super::{count, waits, HandError, HAND_KINDS, ORPHANS};
+use crate::models::{KongKind, Meld, MeldKind, Tile};
+
+/// The identifiers of the winds, from East.
+const WINDS: core::ops::RangeInclusive<usize> = 27..=30;
+
+/// The identifiers of the dragons.
+const DRAGONS: core::ops::RangeInclusive<usize> = 31..=33;
+
+/// The identifiers of the tiles with only green on them: 2, 3, 4, 6 and 8 Tiao, and the
+/// Prosperity dragon.
+const GREENS: [usize; 6] = [19, 20, 21, 23, 25, 32];
+
+/// The rule sets that hands can be scored by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rules {
+    /// Hong Kong rules, scored in faan up to a limit of 13.
+    HK,
+    /// Japanese riichi rules, scored in han and fu.
+    RIICHI,
+    /// The Mahjong Competition Rules, scored in fan.
+    MCR,
+}
+
+impl Rules {
+    /// Returns the name of the unit hands are scored in under these rules.
+    pub const fn unit(&self) -> &'static str {
+        match self {
+            Rules::HK => "faan",
+            Rules::RIICHI => "han",
+            Rules::MCR => "fan",
+        }
+    }
+}
+
+/// How a hand was won.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// The winning tile, which is also one of the concealed tiles of the hand.
+    pub tile: Tile,
+    /// Whether the winning tile was drawn from the wall, rather than claimed from a
+    /// discard.
+    pub self_drawn: bool,
+    /// The wind of the winner's seat.
+    pub seat_wind: Tile,
+    /// The prevailing wind of the round.
+    pub round_wind: Tile,
+}
+
+/// A scoring pattern found in a hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pattern {
+    /// The English name of the pattern.
+    pub name: &'static str,
+    /// The value of the pattern, in the unit of the rules; see [`Rules::unit`].
+    pub value: u32,
+}
+
+/// The score of a winning hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Score {
+    /// The patterns the hand scores.
+    pub patterns: Vec<Pattern>,
+    /// The total value of the patterns, capped at 13 under Hong Kong rules.
+    pub value: u32,
+    /// The fu of the hand, under riichi rules for hands other than yakuman.
+    pub fu: Option<u32>,
+    /// The points won, under riichi rules, before riichi sticks and counters.
+    pub points: Option<u32>,
+}
+
+/// The shape of a set of tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Chow,
+    Pung,
+    Kong,
+}
+
+/// A set of tiles in a hand, by the identifier of its lowest tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Set {
+    shape: Shape,
+    id: usize,
+    /// Whether no tile of the set was claimed, including the winning tile.
+    concealed: bool,
+}
+
+impl Set {
+    /// Create the set for a declared meld.
+    fn from_meld(meld: &Meld) -> Self {
+        let id = meld
+            .tiles()
+            .iter()
+            .map(|tile| usize::from(tile.id()))
+            .min()
+            .expect("Melds should have tiles.");
+        let shape = match meld.kind() {
+            MeldKind::CHOW => Shape::Chow,
+            MeldKind::PUNG => Shape::Pung,
+            MeldKind::KONG(_) => Shape::Kong,
+        };
+        Self {
+            shape,
+            id,
+            concealed: meld.kind() == MeldKind::KONG(KongKind::CONCEALED),
+        }
+    }
+
+    fn is_pung(&self) -> bool {
+        self.shape != Shape::Chow
+    }
+
+    /// Returns whether the set holds a terminal or an honour.
+    fn is_outside(&self) -> bool {
+        match self.shape {
+            Shape::Chow => matches!(self.id % 9, 0 | 6),
+            _ => is_orphan(self.id),
+        }
+    }
+}
+
+/// Returns whether a tile is a terminal or an honour.
+fn is_orphan(id: usize) -> bool {
+    ORPHANS.contains(&id)
+}
+
+/// Returns whether a tile is a number tile from 2 to 8.
+fn is_simple(id: usize) -> bool {
+    !is_orphan(id)
+}
+
+/// The ways a hand can be formed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Form {
+    /// Four sets and a pair.
+    Standard,
+    SevenPairs,
+    ThirteenOrphans,
+}
+
+/// How the winning tile completed the hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Wait {
+    /// Either end of two consecutive tiles.
+    TwoSided,
+    /// The middle of a chow.
+    Closed,
+    /// The 3 of a 1-2-3 chow or the 7 of a 7-8-9 chow.
+    Edge,
+    /// The pair.
+    Single,
+    /// A pung, waiting on either of two pairs.
+    Pung,
+}
+
+/// A winning hand, read as one form with the winning tile in one place.
+#[derive(Debug, Clone)]
+struct Reading {
+    form: Form,
+    sets: Vec<Set>,
+    pairs: Vec<usize>,
+    wait: Wait,
+}
+
+impl Reading {
+    fn pungs(&self) -> impl Iterator<Item = &Set> {
+        self.sets.iter().filter(|set| set.is_pung())
+    }
+
+    fn chows(&self) -> impl Iterator<Item = &Set> {
+        self.sets.iter().filter(|set| !set.is_pung())
+    }
+
+    fn count_pungs(&self, filter: impl Fn(&Set) -> bool) -> usize {
+        self.pungs().filter(|set| filter(set)).count()
+    }
+
+    fn has_chow(&self, id: usize) -> bool {
+        self.chows().any(|set| set.id == id)
+    }
+
+    fn has_pung(&self, id: usize) -> bool {
+        self.pungs().any(|set| set.id == id)
+    }
+
+    /// Returns whether the hand has a pair of any of the given tiles.
+    fn has_pair_in(&self, ids: core::ops::RangeInclusive<usize>) -> bool {
+        self.pairs.iter().any(|pair| ids.contains(pair))
+    }
+
+    /// Returns whether every set and pair holds a terminal or an honour.
+    fn is_outside(&self) -> bool {
+        self.sets.iter().all(Set::is_outside) && self.pairs.iter().all(|pair| is_orphan(*pair))
+    }
+
+    /// Returns whether the hand has chows of the same rank in all three suits.
+    fn has_triple_chow(&self) -> bool {
+        (0..7).any(|rank| (0..3).all(|suit| self.has_chow(suit * 9 + rank)))
+    }
+
+    /// Returns whether the hand has pungs of the same rank in all three suits.
+    fn has_triple_pung(&self) -> bool {
+        (0..9).any(|rank| (0..3).all(|suit| self.has_pung(suit * 9 + rank)))
+    }
+
+    /// Returns whether the hand has chows of 1-2-3, 4-5-6 and 7-8-9 in one suit.
+    fn has_pure_straight(&self) -> bool {
+        (0..3).any(|suit| [0, 3, 6].iter().all(|rank| self.has_chow(suit * 9 + rank)))
+    }
+}
+
+/// What is known about a winning hand, however it is read.
+struct Table {
+    /// Every tile of the hand, including the melds.
+    counts: [u8; HAND_KINDS],
+    /// Whether the hand has no melds other than concealed kongs.
+    concealed: bool,
+    /// The number of declared melds.
+    melds: usize,
+    self_drawn: bool,
+    seat_wind: usize,
+    round_wind: usize,
+    /// The number of flowers and seasons.
+    flowers: u32,
+    /// The number of flowers and seasons of the winner's seat.
+    seat_flowers: u32,
+    /// Whether the winning tile was the only tile the hand could win on.
+    sole_wait: bool,
+}
+
+impl Table {
+    /// Returns whether every tile in the hand satisfies `filter`.
+    fn all(&self, filter: impl Fn(usize) -> bool) -> bool {
+        (0..HAND_KINDS).all(|id| self.counts[id] == 0 || filter(id))
+    }
+
+    /// Returns whether the hand holds any of the given tiles.
+    fn any_in(&self, ids: impl IntoIterator<Item = usize>) -> bool {
+        ids.into_iter().any(|id| self.counts[id] > 0)
+    }
+
+    /// Returns which of the three suits the hand holds.
+    fn suits(&self) -> [bool; 3] {
+        [0, 1, 2].map(|suit| self.any_in(suit * 9..suit * 9 + 9))
+    }
+
+    fn has_honours(&self) -> bool {
+        self.any_in(27..HAND_KINDS)
+    }
+
+    /// Returns whether the hand holds one suit, with or without honours.
+    fn is_one_suit(&self) -> bool {
+        self.suits().iter().filter(|suit| **suit).count() == 1
+    }
+
+    /// Returns whether the hand is 1-1-1-2-3-4-5-6-7-8-9-9-9 of one suit, and one more
+    /// tile of the suit.
+    fn is_nine_gates(&self) -> bool {
+        self.melds == 0
+            && self.is_one_suit()
+            && !self.has_honours()
+            && (0..3).any(|suit| {
+                let counts = &self.counts[suit * 9..suit * 9 + 9];
+                counts[0] >= 3 && counts[8] >= 3 && counts[1..8].iter().all(|count| *count >= 1)
+            })
+    }
+
+    /// Returns whether a tile scores when it forms the pair, under riichi rules.
+    fn is_value_tile(&self, id: usize) -> bool {
+        DRAGONS.contains(&id) || id == self.seat_wind || id == self.round_wind
+    }
+}
+
+/// Collects the patterns of a hand.
+#[derive(Default)]
+struct Patterns(Vec<Pattern>);
+
+impl Patterns {
+    /// Add a pattern, unless its value is zero.
+    fn add(&mut self, name: &'static str, value: u32) {
+        if value > 0 {
+            self.0.push(Pattern { name, value });
+        }
+    }
+
+    /// Add a pattern once for each time it is found.
+    fn add_each(&mut self, name: &'static str, value: u32, count: usize) {
+        (0..count).for_each(|_| self.add(name, value));
+    }
+
+    fn total(&self) -> u32 {
+        self.0.iter().map(|pattern| pattern.value).sum()
+    }
+}
+
+/// Search every way of splitting the tiles from `index` onwards into sets and a pair.
+struct Split {
+    counts: [u8; HAND_KINDS],
+    sets: Vec<Set>,
+    found: Vec<(Vec<Set>, usize)>,
+}
+
+impl Split {
+    fn visit(&mut self, index: usize, pair: Option<usize>) {
+        let Some(index) = (index..HAND_KINDS).find(|i| self.counts[*i] > 0) else {
+            if let Some(pair) = pair {
+                self.found.push((self.sets.clone(), pair));
+            }
+            return;
+        };
+
+        if pair.is_none() && self.counts[index] >= 2 {
+            self.take(&[index, index], None, |split| {
+                split.visit(index, Some(index))
+            });
+        }
+        if self.counts[index] >= 3 {
+            self.take(&[index, index, index], Some(Shape::Pung), |split| {
+                split.visit(index, pair)
+            });
+        }
+        if index < 27 && index % 9 <= 6 && self.counts[index + 1] > 0 && self.counts[index + 2] > 0
+        {
+            self.take(&[index, index + 1, index + 2], Some(Shape::Chow), |split| {
+                split.visit(index, pair)
+            });
+        }
+    }
+
+    /// Remove tiles, as a set of the given shape if any, for the duration of `then`.
+    fn take(&mut self, indices: &[usize], shape: Option<Shape>, then: impl FnOnce(&mut Self)) {
+        indices.iter().for_each(|i| self.counts[*i] -= 1);
+        if let Some(shape) = shape {
+            self.sets.push(Set {
+                shape,
+                id: indices[0],
+                concealed: true,
+            });
+        }
+        then(self);
+        if shape.is_some() {
+            self.sets.pop();
+        }
+        indices.iter().for_each(|i| self.counts[*i] += 1);
+    }
+}
+
+/// Find every reading of a complete hand.
+fn readings(
+    concealed: &[u8; HAND_KINDS],
+    melds: &[Set],
+    win: usize,
+    self_drawn: bool,
+) -> Vec<Reading> {
+    let mut split = Split {
+        counts: *concealed,
+        sets: Vec::new(),
+        found: Vec::new(),
+    };
+    split.visit(0, None);
+
+    let mut readings = Vec::new();
+    for (sets, pair) in split.found {
+        let all_sets = || melds.iter().chain(&sets).copied().collect::<Vec<_>>();
+        if pair == win {
+            readings.push(Reading {
+                form: Form::Standard,
+                sets: all_sets(),
+                pairs: vec![pair],
+                wait: Wait::Single,
+            });
+        }
+        for (index, set) in sets.iter().enumerate() {
+            let position = match set.shape {
+                Shape::Chow if (set.id..set.id + 3).contains(&win) => win - set.id,
+                Shape::Pung if set.id == win => 0,
+                _ => continue,
+            };
+            let wait = match (set.shape, position, set.id % 9) {
+                (Shape::Pung, _, _) => Wait::Pung,
+                (_, 1, _) => Wait::Closed,
+                (_, 0, 6) | (_, 2, 0) => Wait::Edge,
+                _ => Wait::TwoSided,
+            };
+            let mut sets = all_sets();
+            let set = &mut sets[melds.len() + index];
+            set.concealed = self_drawn || set.shape == Shape::Chow;
+            readings.push(Reading {
+                form: Form::Standard,
+                sets,
+                pairs: vec![pair],
+                wait,
+            });
+        }
+    }
+
+    if melds.is_empty() {
+        let pairs = (0..HAND_KINDS)
+            .flat_map(|id| vec![id; usize::from(concealed[id] / 2)])
+            .collect::<Vec<_>>();
+        if pairs.len() == 7 && concealed.iter().all(|count| count % 2 == 0) {
+            readings.push(Reading {
+                form: Form::SevenPairs,
+                sets: Vec::new(),
+                pairs,
+                wait: Wait::Single,
+            });
+        }
+        if ORPHANS.iter().all(|id| concealed[*id] > 0)
+            && (0..HAND_KINDS).all(|id| concealed[id] == 0 || is_orphan(id))
+        {
+            readings.push(Reading {
+                form: Form::ThirteenOrphans,
+                sets: Vec::new(),
+                pairs: Vec::new(),
+                wait: Wait::Single,
+            });
+        }
+    }
+    readings
+}
+
+/// Score a reading of a hand under Hong Kong rules.
+fn hong_kong(table: &Table, reading: &Reading) -> Option<Score> {
+    let mut limits = Patterns::default();
+    match reading.form {
+        Form::SevenPairs => return None,
+        Form::ThirteenOrphans => limits.add("Thirteen orphans", 13),
+        Form::Standard => {
+            if table.is_nine_gates() {
+                limits.add("Nine gates", 13);
+            }
+            if reading.count_pungs(|set| WINDS.contains(&set.id)) == 4 {
+                limits.add("Great four winds", 13);
+            }
+            if reading.count_pungs(|set| set.concealed) == 4 {
+                limits.add("Four concealed pungs", 13);
+            }
+            if reading.count_pungs(|set| set.shape == Shape::Kong) == 4 {
+                limits.add("Four kongs", 13);
+            }
+            if table.all(|id| id >= 27) {
+                limits.add("All honours", 13);
+            }
+            if table.all(|id| is_orphan(id) && id < 27) {
+                limits.add("All terminals", 13);
+            }
+        }
+    }
+    if !limits.0.is_empty() {
+        return Some(Score {
+            patterns: limits.0,
+            value: 13,
+            fu: None,
+            points: None,
+        });
+    }
+
+    let mut patterns = Patterns::default();
+    if table.self_drawn {
+        patterns.add("Self-drawn", 1);
+    }
+    if table.concealed {
+        patterns.add("Concealed hand", 1);
+    }
+    if reading.pungs().count() == 0 {
+        patterns.add("All chows", 1);
+    }
+    if reading.chows().count() == 0 {
+        patterns.add("All pungs", 3);
+    }
+    if table.is_one_suit() {
+        if table.has_honours() {
+            patterns.add("Mixed one suit", 3);
+        } else {
+            patterns.add("Pure one suit", 7);
+        }
+    }
+    if table.all(is_orphan) {
+        patterns.add("Mixed terminals", 1);
+    }
+
+    let dragon_pungs = reading.count_pungs(|set| DRAGONS.contains(&set.id));
+    if dragon_pungs == 3 {
+        patterns.add("Great three dragons", 8);
+    } else if dragon_pungs == 2 && reading.has_pair_in(DRAGONS) {
+        patterns.add("Small three dragons", 5);
+    } else {
+        patterns.add_each("Dragon pung", 1, dragon_pungs);
+    }
+
+    if reading.count_pungs(|set| WINDS.contains(&set.id)) == 3 && reading.has_pair_in(WINDS) {
+        patterns.add("Small four winds", 10);
+    } else {
+        if reading.has_pung(table.seat_wind) {
+            patterns.add("Seat wind", 1);
+        }
+        if reading.has_pung(table.round_wind) {
+            patterns.add("Prevailing wind", 1);
+        }
+    }
+    patterns.add_each("Seat flower", 1, table.seat_flowers as usize);
+
+    Some(Score {
+        value: patterns.total().min(13),
+        patterns: patterns.0,
+        fu: None,
+        points: None,
+    })
+}
+
+/// The fu of a reading of a hand under riichi rules.
+fn riichi_fu(table: &Table, reading: &Reading, pinfu: bool) -> u32 {
+    match reading.form {
+        Form::SevenPairs => return 25,
+        Form::ThirteenOrphans => return 30,
+        Form::Standard if pinfu => return if table.self_drawn { 20 } else { 30 },
+        Form::Standard => {}
+    }
+
+    let mut fu = 20;
+    if table.concealed && !table.self_drawn {
+        fu += 10;
+    }
+    if table.self_drawn {
+        fu += 2;
+    }
+    fu += reading
+        .pungs()
+        .map(|set| {
+            let orphan = if is_orphan(set.id) { 2 } else { 1 };
+            let concealed = if set.concealed { 2 } else { 1 };
+            let kong = if set.shape == Shape::Kong { 4 } else { 1 };
+            2 * orphan * concealed * kong
+        })
+        .sum::<u32>();
+    fu += reading
+        .pairs
+        .iter()
+        .map(|pair| {
+            2 * (u32::from(DRAGONS.contains(pair))
+                + u32::from(*pair == table.seat_wind)
+                + u32::from(*pair == table.round_wind))
+        })
+        .sum::<u32>();
+    if matches!(reading.wait, Wait::Closed | Wait::Edge | Wait::Single) {
+        fu += 2;
+    }
+
+    if fu == 20 {
+        30
+    } else {
+        fu.div_ceil(10) * 10
+    }
+}
+
+/// The points won for a riichi hand.
+fn riichi_points(han: u32, fu: u32, yakuman: u32, dealer: bool, self_drawn: bool) -> u32 {
+    let basic = match (yakuman, han) {
+        (1.., _) => 8000 * yakuman,
+        (_, 0) => return 0,
+        (_, 13..) => 8000,
+        (_, 11..) => 6000,
+        (_, 8..) => 4000,
+        (_, 6..) => 3000,
+        (_, 5) => 2000,
+        _ => (fu << (han + 2)).min(2000),
+    };
+    let round_up = |points: u32| points.div_ceil(100) * 100;
+
+    match (dealer, self_drawn) {
+        (true, true) => 3 * round_up(2 * basic),
+        (true, false) => round_up(6 * basic),
+        (false, true) => 2 * round_up(basic) + round_up(2 * basic),
+        (false, false) => round_up(4 * basic),
+    }
+}
+
+/// Score a reading of a hand under riichi rules.
+fn riichi(table: &Table, reading: &Reading) -> Option<Score> {
+    let dealer = table.seat_wind == 27;
+    let open = !table.concealed;
+    let closed_only = |value: u32| if open { 0 } else { value };
+    let reduced = |value: u32| if open { value - 1 } else { value };
+
+    let mut yakuman = Patterns::default();
+    match reading.form {
+        Form::ThirteenOrphans => yakuman.add("Thirteen orphans", 13),
+        // Four of a kind cannot be two of the seven pairs.
+        Form::SevenPairs if reading.pairs.windows(2).any(|pair| pair[0] == pair[1]) => return None,
+        Form::SevenPairs => {}
+        Form::Standard => {
+            if reading.count_pungs(|set| DRAGONS.contains(&set.id)) == 3 {
+                yakuman.add("Big three dragons", 13);
+            }
+            if reading.count_pungs(|set| set.concealed) == 4 {
+                yakuman.add("Four concealed triplets", 13);
+            }
+            match reading.count_pungs(|set| WINDS.contains(&set.id)) {
+                4 => yakuman.add("Big four winds", 13),
+                3 if reading.has_pair_in(WINDS) => yakuman.add("Little four winds", 13),
+                _ => {}
+            }
+            if reading.count_pungs(|set| set.shape == Shape::Kong) == 4 {
+                yakuman.add("Four quads", 13);
+            }
+            if table.is_nine_gates() {
+                yakuman.add("Nine gates", 13);
+            }
+        }
+    }
+    if reading.form != Form::ThirteenOrphans {
+        if table.all(|id| id >= 27) {
+            yakuman.add("All honours", 13);
+        }
+        if table.all(|id| is_orphan(id) && id < 27) {
+            yakuman.add("All terminals", 13);
+        }
+        if table.all(|id| GREENS.contains(&id)) {
+            yakuman.add("All green", 13);
+        }
+    }
+    if !yakuman.0.is_empty() {
+        let value = yakuman.total();
+        return Some(Score {
+            points: Some(riichi_points(
+                value,
+                0,
+                value / 13,
+                dealer,
+                table.self_drawn,
+            )),
+            patterns: yakuman.0,
+            value,
+            fu: None,
+        });
+    }
+
+    let mut patterns = Patterns::default();
+    if table.concealed && table.self_drawn {
+        patterns.add("Fully concealed hand", 1);
+    }
+    let pinfu = reading.form == Form::Standard
+        && table.concealed
+        && reading.pungs().count() == 0
+        && !reading.pairs.iter().any(|pair| table.is_value_tile(*pair))
+        && reading.wait == Wait::TwoSided;
+    if pinfu {
+        patterns.add("Pinfu", 1);
+    }
+    if table.all(is_simple) {
+        patterns.add("All simples", 1);
+    }
+
+    if reading.form == Form::SevenPairs {
+        patterns.add("Seven pairs", 2);
+    } else {
+        let identical_chows = (0..27)
+            .map(|id| reading.chows().filter(|set| set.id == id).count() / 2)
+            .sum::<usize>();
+        match identical_chows {
+            2 => patterns.add("Twice pure double sequence", closed_only(3)),
+            1 => patterns.add("Pure double sequence", closed_only(1)),
+            _ => {}
+        }
+
+        patterns.add_each(
+            "Dragon triplet",
+            1,
+            reading.count_pungs(|set| DRAGONS.contains(&set.id)),
+        );
+        if reading.has_pung(table.seat_wind) {
+            patterns.add("Seat wind", 1);
+        }
+        if reading.has_pung(table.round_wind) {
+            patterns.add("Prevalent wind", 1);
+        }
+
+        if reading.has_triple_chow() {
+            patterns.add("Mixed triple sequence", reduced(2));
+        }
+        if reading.has_pure_straight() {
+            patterns.add("Pure straight", reduced(2));
+        }
+        if reading.chows().count() == 0 {
+            patterns.add("All triplets", 2);
+        }
+        if reading.count_pungs(|set| set.concealed) == 3 {
+            patterns.add("Three concealed triplets", 2);
+        }
+        if reading.has_triple_pung() {
+            patterns.add("Triple triplets", 2);
+        }
+        if reading.count_pungs(|set| set.shape == Shape::Kong) == 3 {
+            patterns.add("Three quads", 2);
+        }
+        if reading.count_pungs(|set| DRAGONS.contains(&set.id)) == 2 && reading.has_pair_in(DRAGONS)
+        {
+            patterns.add("Little three dragons", 2);
+        }
+        if reading.is_outside() && !table.all(is_orphan) {
+            if table.has_honours() {
+                patterns.add("Half outside hand", reduced(2));
+            } else {
+                patterns.add("Fully outside hand", reduced(3));
+            }
+        }
+    }
+    if table.all(is_orphan) {
+        patterns.add("All terminals and honours", 2);
+    }
+    if table.is_one_suit() {
+        if table.has_honours() {
+            patterns.add("Half flush", reduced(3));
+        } else {
+            patterns.add("Full flush", reduced(6));
+        }
+    }
+
+    let han = patterns.total();
+    let fu = riichi_fu(table, reading, pinfu);
+    Some(Score {
+        patterns: patterns.0,
+        value: han,
+        fu: Some(fu),
+        points: Some(riichi_points(han, fu, 0, dealer, table.self_drawn)),
+    })
+}
+
+/// Score a reading of a hand under the Mahjong Competition Rules.
+fn mcr(table: &Table, reading: &Reading) -> Option<Score> {
+    let mut patterns = Patterns::default();
+    let suits = table.suits();
+    let winds = reading.count_pungs(|set| WINDS.contains(&set.id));
+    let dragons = reading.count_pungs(|set| DRAGONS.contains(&set.id));
+    let concealed_pungs = reading.count_pungs(|set| set.concealed);
+    let kongs = reading.count_pungs(|set| set.shape == Shape::Kong);
+    let concealed_kongs = reading.count_pungs(|set| set.shape == Shape::Kong && set.concealed);
+
+    // Patterns implied by a pattern already counted are left out, as the rules require.
+    let mut no_all_pungs = false;
+    let mut no_outside = false;
+    let mut no_orphan_pungs = false;
+    let mut no_wind_pungs = false;
+    let mut no_winds = false;
+    let mut no_dragons = false;
+    let mut no_no_honours = false;
+    let mut no_concealed = false;
+    let mut no_single_wait = false;
+    let mut no_kongs = false;
+    let mut no_categories = false;
+
+    match reading.form {
+        Form::ThirteenOrphans => {
+            patterns.add("Thirteen Orphans", 88);
+            (no_concealed, no_single_wait, no_categories) = (true, true, true);
+        }
+        Form::SevenPairs => {
+            patterns.add("Seven Pairs", 24);
+            no_concealed = true;
+            no_single_wait = true;
+        }
+        Form::Standard => {
+            if winds == 4 {
+                patterns.add("Big Four Winds", 88);
+                (no_all_pungs, no_winds, no_orphan_pungs) = (true, true, true);
+            } else if winds == 3 && reading.has_pair_in(WINDS) {
+                patterns.add("Little Four Winds", 64);
+                no_wind_pungs = true;
+            } else if winds == 3 {
+                patterns.add("Big Three Winds", 12);
+                no_wind_pungs = true;
+            }
+            if dragons == 3 {
+                patterns.add("Big Three Dragons", 88);
+                no_dragons = true;
+            } else if dragons == 2 && reading.has_pair_in(DRAGONS) {
+                patterns.add("Little Three Dragons", 64);
+                no_dragons = true;
+            }
+            if table.is_nine_gates() {
+                patterns.add("Nine Gates", 88);
+                (no_concealed, no_orphan_pungs, no_no_honours) = (true, true, true);
+            }
+            match kongs {
+                4 => {
+                    patterns.add("Four Kongs", 88);
+                    (no_kongs, no_all_pungs, no_single_wait) = (true, true, true);
+                }
+                3 => {
+                    patterns.add("Three Kongs", 32);
+                    no_kongs = true;
+                }
+                _ => {}
+            }
+            if concealed_pungs == 4 {
+                patterns.add("Four Concealed Pungs", 64);
+                (no_all_pungs, no_concealed) = (true, true);
+            }
+        }
+    }
+
+    if table.all(|id| GREENS.contains(&id)) {
+        patterns.add("All Green", 88);
+    }
+    if table.all(|id| is_orphan(id) && id < 27) {
+        patterns.add("All Terminals", 64);
+        (no_all_pungs, no_outside, no_orphan_pungs, no_no_honours) = (true, true, true, true);
+    } else if table.all(|id| id >= 27) {
+        patterns.add("All Honours", 64);
+        (no_all_pungs, no_outside, no_orphan_pungs) = (true, true, true);
+    } else if table.all(is_orphan) && !no_categories {
+        patterns.add("All Terminals and Honours", 32);
+        (no_all_pungs, no_outside, no_orphan_pungs) = (true, true, true);
+    }
+
+    if table.is_one_suit() {
+        if !table.has_honours() {
+            if !table.is_nine_gates() {
+                patterns.add("Full Flush", 24);
+            }
+            no_no_honours = true;
+        } else if !table.all(|id| GREENS.contains(&id)) {
+            patterns.add("Half Flush", 6);
+        }
+    }
+    if reading.has_pure_straight() {
+        patterns.add("Pure Straight", 16);
+    }
+    if [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ]
+    .iter()
+    .any(|suits| (0..3).all(|index| reading.has_chow(suits[index] * 9 + index * 3)))
+    {
+        patterns.add("Mixed Straight", 8);
+    }
+    if reading.has_triple_chow() {
+        patterns.add("Mixed Triple Chow", 8);
+    }
+    let triple_pung = reading.has_triple_pung();
+    if triple_pung {
+        patterns.add("Triple Pung", 16);
+    }
+    if concealed_pungs == 3 {
+        patterns.add("Three Concealed Pungs", 16);
+    }
+
+    if reading.form == Form::Standard && reading.chows().count() == 0 && !no_all_pungs {
+        patterns.add("All Pungs", 6);
+    }
+    if !no_categories
+        && suits.iter().all(|suit| *suit)
+        && table.any_in(WINDS)
+        && table.any_in(DRAGONS)
+    {
+        patterns.add("All Types", 6);
+    }
+    if !no_dragons && dragons == 2 {
+        patterns.add("Two Dragon Pungs", 6);
+    } else if !no_dragons {
+        patterns.add_each("Dragon Pung", 2, dragons);
+    }
+    if table.melds == 4 && !table.concealed && !table.self_drawn && reading.wait == Wait::Single {
+        patterns.add("Melded Hand", 6);
+        no_single_wait = true;
+    }
+    if reading.form == Form::Standard && reading.is_outside() && !no_outside {
+        patterns.add("Outside Hand", 4);
+    }
+
+    if !no_winds {
+        if reading.has_pung(table.round_wind) {
+            patterns.add("Prevalent Wind", 2);
+        }
+        if reading.has_pung(table.seat_wind) {
+            patterns.add("Seat Wind", 2);
+        }
+    }
+    let fully_concealed = table.concealed && table.self_drawn && !no_concealed;
+    if fully_concealed {
+        patterns.add("Fully Concealed Hand", 4);
+    } else if table.concealed && !no_concealed {
+        patterns.add("Concealed Hand", 2);
+    }
+    if reading.form == Form::Standard
+        && reading.pungs().count() == 0
+        && !reading.has_pair_in(27..=33)
+    {
+        patterns.add("All Chows", 2);
+        no_no_honours = true;
+    }
+    if table.all(is_simple) {
+        patterns.add("All Simples", 2);
+        no_no_honours = true;
+    }
+    if concealed_pungs == 2 {
+        patterns.add("Two Concealed Pungs", 2);
+    }
+    if !triple_pung && !table.all(|id| is_orphan(id) && id < 27) {
+        let double_pungs = (0..9)
+            .filter(|rank| {
+                (0..3)
+                    .filter(|suit| reading.has_pung(suit * 9 + rank))
+                    .count()
+                    == 2
+            })
+            .count();
+        patterns.add_each("Double Pung", 2, double_pungs);
+    }
+    let tile_hogs = (0..HAND_KINDS)
+        .filter(|id| {
+            table.counts[*id] == 4
+                && !reading
+                    .sets
+                    .iter()
+                    .any(|set| set.shape == Shape::Kong && set.id == *id)
+        })
+        .count();
+    patterns.add_each("Tile Hog", 2, tile_hogs);
+
+    if !no_kongs {
+        if kongs == 2 && concealed_kongs == 2 {
+            patterns.add("Two Concealed Kongs", 8);
+        } else if kongs == 2 && concealed_kongs == 0 {
+            patterns.add("Two Melded Kongs", 4);
+        } else {
+            patterns.add_each("Concealed Kong", 2, concealed_kongs);
+            patterns.add_each("Melded Kong", 1, kongs - concealed_kongs);
+        }
+    }
+    if !no_orphan_pungs {
+        let orphan_pungs = reading.count_pungs(|set| {
+            (is_orphan(set.id) && set.id < 27)
+                || (WINDS.contains(&set.id)
+                    && !no_wind_pungs
+                    && set.id != table.seat_wind
+                    && set.id != table.round_wind)
+        });
+        patterns.add_each("Pung of Terminals or Honours", 1, orphan_pungs);
+    }
+    if suits.iter().filter(|suit| **suit).count() == 2 {
+        patterns.add("One Voided Suit", 1);
+    }
+    if !table.has_honours() && !no_no_honours {
+        patterns.add("No Honours", 1);
+    }
+    if table.sole_wait {
+        match reading.wait {
+            Wait::Edge => patterns.add("Edge Wait", 1),
+            Wait::Closed => patterns.add("Closed Wait", 1),
+            Wait::Single if !no_single_wait => patterns.add("Single Wait", 1),
+            _ => {}
+        }
+    }
+    if table.self_drawn && !fully_concealed {
+        patterns.add("Self-Drawn", 1);
+    }
+    patterns.add_each("Flower Tiles", 1, table.flowers as usize);
+
+    Some(Score {
+        value: patterns.total(),
+        patterns: patterns.0,
+        fu: None,
+        points: None,
+    })
+}
+
+/// Returns the best score of a winning hand under the given rules.
+///
+/// `tiles` are the concealed tiles, including the winning tile and any flowers and
+/// seasons, and `melds` the declared melds, so that the hand has 14 tiles other than
+/// flowers and seasons, counting each kong as three. Every way of reading the hand is
+/// scored, and the highest score is returned.
+///
+/// Patterns that depend on the course of play are not counted, as they cannot be told
+/// from the hand: among others, riichi declarations, dora, winning on the last tile or
+/// on a replacement tile, and robbing a kong. Otherwise:
+///
+/// - Under [`Rules::HK`], the common table of faan is used, with limit hands
+///   worth 13. Seven pairs is not a winning hand.
+/// - Under [`Rules::RIICHI`], a hand without any yaku scores `0`. Yakuman are worth 13
+///   han each and are not combined with other yaku.
+/// - Under [`Rules::MCR`], patterns made of several chows are counted only for straights
+///   and the mixed triple chow, and the knitted and shifted patterns are not counted.
+///   The 8 fan minimum is not enforced.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::Tile;
+/// use mahjong_tiles::hand::{parse_hand, score, Rules, Win};
+///
+/// let hand = parse_hand("234m456p678s234s88p").unwrap();
+/// let win = Win {
+///     tile: Tile::TIAO(4),
+///     self_drawn: false,
+///     seat_wind: Tile::SOUTH,
+///     round_wind: Tile::EAST,
+/// };
+/// let score = score(&hand, &[], &win, Rules::RIICHI).unwrap();
+///
+/// assert_eq!(score.value, 2);
+/// assert_eq!(score.fu, Some(30));
+/// assert_eq!(score.points, Some(2000));
+/// ```
+pub fn score(tiles: &[Tile], melds: &[Meld], win: &Win, rules: Rules) -> Result<Score, HandError> {
+    let wind = |tile: Tile| match tile.checked_id().map(usize::from) {
+        Some(id) if WINDS.contains(&id) => Ok(id),
+        _ => Err(HandError::InvalidWind(tile)),
+    };
+    let seat_wind = wind(win.seat_wind)?;
+    let round_wind = wind(win.round_wind)?;
+
+    let concealed = count(tiles)?;
+    let size = concealed
+        .iter()
+        .map(|count| usize::from(*count))
+        .sum::<usize>();
+    if size + 3 * melds.len() != 14 {
+        return Err(HandError::InvalidSize(size));
+    }
+    if win.tile.is_flower() || !tiles.contains(&win.tile) {
+        return Err(HandError::WinningTileNotInHand(win.tile));
+    }
+    let win_id = usize::from(win.tile.id());
+
+    let mut counts = concealed;
+    for tile in melds.iter().flat_map(Meld::tiles) {
+        let count = &mut counts[usize::from(tile.id())];
+        *count += 1;
+        if *count > 4 {
+            return Err(HandError::TooManyCopies(*tile));
+        }
+    }
+    let flowers = tiles.iter().filter(|tile| tile.is_flower());
+    let mut rest = tiles.to_vec();
+    rest.remove(
+        rest.iter()
+            .position(|tile| *tile == win.tile)
+            .expect("The winning tile should be in the hand."),
+    );
+
+    let table = Table {
+        counts,
+        concealed: melds.iter().all(Meld::is_concealed),
+        melds: melds.len(),
+        self_drawn: win.self_drawn,
+        seat_wind,
+        round_wind,
+        flowers: flowers.clone().count() as u32,
+        seat_flowers: flowers
+            .filter(|tile| tile.direction() == win.seat_wind.direction())
+            .count() as u32,
+        sole_wait: waits(&rest).is_ok_and(|waits| waits == [win.tile]),
+    };
+
+    let meld_sets = melds.iter().map(Set::from_meld).collect::<Vec<_>>();
+    readings(&concealed, &meld_sets, win_id, win.self_drawn)
+        .iter()
+        .filter_map(|reading| match rules {
+            Rules::HK => hong_kong(&table, reading),
+            Rules::RIICHI => riichi(&table, reading),
+            Rules::MCR => mcr(&table, reading),
+        })
+        .max_by_key(|score| (score.points, score.value, score.fu))
+        .ok_or(HandError::NotComplete)
+}
+
+#[cfg(test)]
+mod test_score {
+    use super::*;
+    use crate::hand::parse_hand;
+
+    /// Score a concealed hand, won on the last tile written.
+    fn score_hand(hand: &str, self_drawn: bool, seat_wind: Tile, rules: Rules) -> Score {
+        score_melded(hand, &[], self_drawn, seat_wind, rules).unwrap()
+    }
+
+    fn score_melded(
+        hand: &str,
+        melds: &[Meld],
+        self_drawn: bool,
+        seat_wind: Tile,
+        rules: Rules,
+    ) -> Result<Score, HandError> {
+        let tiles = parse_hand(hand).unwrap();
+        let win = Win {
+            tile: *tiles.iter().rfind(|tile| !tile.is_flower()).unwrap(),
+            self_drawn,
+            seat_wind,
+            round_wind: Tile::EAST,
+        };
+        score(&tiles, melds, &win, rules)
+    }
+
+    fn names(score: &Score) -> Vec<&'static str> {
+        score.patterns.iter().map(|pattern| pattern.name).collect()
+    }
+
+    macro_rules! create_test {
+        ($name:ident, $hand:literal, $self_drawn:literal, $seat:expr, $rules:expr, $value:literal, $patterns:expr) => {
+            #[test]
+            fn $name() {
+                let score = score_hand($hand, $self_drawn, $seat, $rules);
+
+                assert_eq!(names(&score), $patterns);
+                assert_eq!(score.value, $value);
+            }
+        };
+    }
+
+    create_test!(
+        riichi_pinfu_all_simples,
+        "234m456p678s88p234s",
+        false,
+        Tile::SOUTH,
+        Rules::RIICHI,
+        2,
+        ["Pinfu", "All simples"]
+    );
+    create_test!(
+        riichi_no_yaku,
+        "123m456p789s234s55p",
+        false,
+        Tile::SOUTH,
+        Rules::RIICHI,
+        0,
+        [] as [&str; 0]
+    );
+    create_test!(
+        riichi_pure_double_sequence,
+        "123m123m456p11z789s",
+        true,
+        Tile::EAST,
+        Rules::RIICHI,
+        2,
+        ["Fully concealed hand", "Pure double sequence"]
+    );
+    create_test!(
+        riichi_full_flush,
+        "234m456m678m22m345m",
+        false,
+        Tile::SOUTH,
+        Rules::RIICHI,
+        8,
+        ["Pinfu", "All simples", "Full flush"]
+    );
+    create_test!(
+        riichi_nine_gates,
+        "1112345678999m5m",
+        false,
+        Tile::SOUTH,
+        Rules::RIICHI,
+        13,
+        ["Nine gates"]
+    );
+    create_test!(
+        riichi_seven_pairs,
+        "1122m3344p5566s77z",
+        false,
+        Tile::SOUTH,
+        Rules::RIICHI,
+        2,
+        ["Seven pairs"]
+    );
+    create_test!(
+        riichi_thirteen_orphans,
+        "19m19p19s12345677z",
+        false,
+        Tile::SOUTH,
+        Rules::RIICHI,
+        13,
+        ["Thirteen orphans"]
+    );
+    create_test!(
+        riichi_half_outside,
+        "123m789m999p11z22z2z",
+        false,
+        Tile::SOUTH,
+        Rules::RIICHI,
+        3,
+        ["Seat wind", "Half outside hand"]
+    );
+    create_test!(
+        hong_kong_pure_one_suit,
+        "123m456m789m234m9m9m",
+        false,
+        Tile::SOUTH,
+        Rules::HK,
+        9,
+        ["Concealed hand", "All chows", "Pure one suit"]
+    );
+    create_test!(
+        hong_kong_great_three_dragons,
+        "555z666z777z123m9p9p",
+        false,
+        Tile::SOUTH,
+        Rules::HK,
+        9,
+        ["Concealed hand", "Great three dragons"]
+    );
+    create_test!(
+        hong_kong_limit,
+        "111m333m555m777m9m9m",
+        true,
+        Tile::SOUTH,
+        Rules::HK,
+        13,
+        ["Four concealed pungs"]
+    );
+    create_test!(
+        hong_kong_seat_flower,
+        "123m456p789s234s5p2f5p",
+        true,
+        Tile::SOUTH,
+        Rules::HK,
+        4,
+        ["Self-drawn", "Concealed hand", "All chows", "Seat flower"]
+    );
+    create_test!(
+        mcr_pure_straight,
+        "123m456m789m123p5p5p",
+        false,
+        Tile::SOUTH,
+        Rules::MCR,
+        22,
+        [
+            "Pure Straight",
+            "Concealed Hand",
+            "All Chows",
+            "One Voided Suit",
+            "Single Wait"
+        ]
+    );
+    create_test!(
+        mcr_seven_pairs,
+        "1122m3344p5566s77z",
+        false,
+        Tile::SOUTH,
+        Rules::MCR,
+        24,
+        ["Seven Pairs"]
+    );
+    create_test!(
+        mcr_thirteen_orphans,
+        "19m19p19s12345677z",
+        true,
+        Tile::SOUTH,
+        Rules::MCR,
+        89,
+        ["Thirteen Orphans", "Self-Drawn"]
+    );
+    create_test!(
+        mcr_big_four_winds,
+        "111z222z333z444z5m5m",
+        false,
+        Tile::SOUTH,
+        Rules::MCR,
+        159,
+        [
+            "Big Four Winds",
+            "Four Concealed Pungs",
+            "Half Flush",
+            "Single Wait"
+        ]
+    );
+
+    #[test]
+    fn riichi_points() {
+        let ron = score_hand("234m456p678s88p234s", false, Tile::SOUTH, Rules::RIICHI);
+        assert_eq!((ron.fu, ron.points), (Some(30), Some(2000)));
+
+        let tsumo = score_hand("123m123m456p11z789s", true, Tile::EAST, Rules::RIICHI);
+        assert_eq!((tsumo.fu, tsumo.points), (Some(30), Some(3000)));
+
+        let pairs = score_hand("1122m3344p5566s77z", false, Tile::SOUTH, Rules::RIICHI);
+        assert_eq!((pairs.fu, pairs.points), (Some(25), Some(1600)));
+
+        let orphans = score_hand("19m19p19s12345677z", false, Tile::EAST, Rules::RIICHI);
+        assert_eq!((orphans.fu, orphans.points), (None, Some(48000)));
+    }
+
+    #[test]
+    fn riichi_open_hand() {
+        let melds = [Meld::pung(Tile::CENTRAL, 0).unwrap()];
+        let score = score_melded("234m456p678s2p2p", &melds, true, Tile::SOUTH, Rules::RIICHI);
+
+        assert_eq!(
+            score,
+            Ok(Score {
+                patterns: vec![Pattern {
+                    name: "Dragon triplet",
+                    value: 1
+                }],
+                value: 1,
+                fu: Some(30),
+                points: Some(1100),
+            })
+        );
+    }
+
+    #[test]
+    fn riichi_ron_pung_is_not_concealed() {
+        let ron = score_hand("222m444m666p33p88s8s", false, Tile::SOUTH, Rules::RIICHI);
+        let tsumo = score_hand("222m444m666p33p88s8s", true, Tile::SOUTH, Rules::RIICHI);
+
+        assert!(names(&ron).contains(&"Three concealed triplets"));
+        assert!(names(&tsumo).contains(&"Four concealed triplets"));
+    }
+
+    #[test]
+    fn hong_kong_seven_pairs() {
+        assert_eq!(
+            score_melded("1122m3344p5566s77z", &[], false, Tile::SOUTH, Rules::HK),
+            Err(HandError::NotComplete)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            score_melded("123m456p789s1122z", &[], false, Tile::SOUTH, Rules::MCR),
+            Err(HandError::InvalidSize(13))
+        );
+        assert_eq!(
+            score_melded("123m456p789s1134z5z", &[], false, Tile::SOUTH, Rules::MCR),
+            Err(HandError::NotComplete)
+        );
+        assert_eq!(
+            score_melded("123m456p789s11z22z", &[], false, Tile::CENTRAL, Rules::MCR),
+            Err(HandError::InvalidWind(Tile::CENTRAL))
+        );
+
+        let tiles = parse_hand("123m456p789s11z222z").unwrap();
+        let win = Win {
+            tile: Tile::NORTH,
+            self_drawn: false,
+            seat_wind: Tile::EAST,
+            round_wind: Tile::EAST,
+        };
+        assert_eq!(
+            score(&tiles, &[], &win, Rules::RIICHI),
+            Err(HandError::WinningTileNotInHand(Tile::NORTH))
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! Shanten, the number of tiles a hand is away from ready, and its waits.
+
+use alloc::vec::Vec;
+
+use super::HandError;
+use crate::models::Tile;
+
+/// The number of kinds of tiles that make up a hand, excluding flowers and seasons.
+pub(super) const HAND_KINDS: usize = 34;
+
+/// The identifiers of the terminals and honours, for thirteen orphans.
+pub(super) const ORPHANS: [usize; 13] = [0, 8, 9, 17, 18, 26, 27, 28, 29, 30, 31, 32, 33];
+
+/// Count the tiles of each kind in a hand, ignoring flowers and seasons.
+pub(super) fn count(tiles: &[Tile]) -> Result<[u8; HAND_KINDS], HandError> {
+    let mut counts = [0; HAND_KINDS];
+    for tile in tiles.iter().filter(|tile| !tile.is_flower()) {
+        let id = tile.checked_id().ok_or(HandError::InvalidTile(*tile))?;
+        let count = &mut counts[usize::from(id)];
+        *count += 1;
+        if *count > 4 {
+            return Err(HandError::TooManyCopies(*tile));
+        }
+    }
+
+    let size = counts
+        .iter()
+        .map(|count| usize::from(*count))
+        .sum::<usize>();
+    if size % 3 == 0 || size > 14 {
+        return Err(HandError::InvalidSize(size));
+    }
+    Ok(counts)
+}
+
+/// Search every way of splitting the tiles from `index` onwards into sets, partial
+/// sets and a pair, keeping the lowest shanten in `best`.
+struct Search {
+    counts: [u8; HAND_KINDS],
+    sets_needed: i8,
+    best: i8,
+}
+
+impl Search {
+    fn visit(&mut self, index: usize, sets: i8, partials: i8, pair: bool) {
+        let Some(index) = (index..HAND_KINDS).find(|i| self.counts[*i] > 0) else {
+            let partials = partials.min(self.sets_needed - sets);
+            let shanten = 2 * (self.sets_needed - sets) - partials - i8::from(pair);
+            self.best = self.best.min(shanten);
+            return;
+        };
+        let is_suited = index < 27;
+        let position = index % 9;
+
+        if self.counts[index] >= 3 {
+            self.take(&[index, index, index], |search| {
+                search.visit(index, sets + 1, partials, pair)
+            });
+        }
+        if is_suited && position <= 6 && self.counts[index + 1] > 0 && self.counts[index + 2] > 0 {
+            self.take(&[index, index + 1, index + 2], |search| {
+                search.visit(index, sets + 1, partials, pair)
+            });
+        }
+        if self.counts[index] >= 2 {
+            if !pair {
+                self.take(&[index, index], |search| {
+                    search.visit(index, sets, partials, true)
+                });
+            }
+            self.take(&[index, index], |search| {
+                search.visit(index, sets, partials + 1, pair)
+            });
+        }
+        if is_suited && position <= 7 && self.counts[index + 1] > 0 {
+            self.take(&[index, index + 1], |search| {
+                search.visit(index, sets, partials + 1, pair)
+            });
+        }
+        if is_suited && position <= 6 && self.counts[index + 2] > 0 {
+            self.take(&[index, index + 2], |search| {
+                search.visit(index, sets, partials + 1, pair)
+            });
+        }
+        self.take(&[index], |search| search.visit(index, sets, partials, pair));
+    }
+
+    /// Remove tiles for the duration of `then`.
+    fn take(&mut self, indices: &[usize], then: impl FnOnce(&mut Self)) {
+        indices.iter().for_each(|i| self.counts[*i] -= 1);
+        then(self);
+        indices.iter().for_each(|i| self.counts[*i] += 1);
+    }
+}
+
+/// The shanten of a hand of four sets and a pair, with `sets_needed` sets left to
+/// complete from the concealed tiles.
+fn standard_shanten(counts: &[u8; HAND_KINDS], sets_needed: i8) -> i8 {
+    let mut search = Search {
+        counts: *counts,
+        sets_needed,
+        best: 2 * sets_needed,
+    };
+    search.visit(0, 0, 0, false);
+    search.best
+}
+
+/// The shanten of a hand of seven distinct pairs.
+fn seven_pairs_shanten(counts: &[u8; HAND_KINDS]) -> i8 {
+    let pairs = counts.iter().filter(|count| **count >= 2).count() as i8;
+    let kinds = counts.iter().filter(|count| **count > 0).count() as i8;
+
+    6 - pairs + (7 - kinds).max(0)
+}
+
+/// The shanten of a hand of one of each terminal and honour, and a pair of any one.
+fn thirteen_orphans_shanten(counts: &[u8; HAND_KINDS]) -> i8 {
+    let kinds = ORPHANS.iter().filter(|i| counts[**i] > 0).count() as i8;
+    let pair = ORPHANS.iter().any(|i| counts[*i] >= 2);
+
+    13 - kinds - i8::from(pair)
+}
+
+/// Returns the shanten of a hand: the number of tiles it needs to be ready, less
+/// one, so that `0` is ready and `-1` is complete.
+///
+/// The hand is the concealed tiles only, with 13 or 14 tiles if there are no melds
+/// and 3 fewer for each meld. Flowers and seasons are ignored. Seven pairs and
+/// thirteen orphans are considered for hands without melds.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::hand::{parse_hand, shanten};
+///
+/// assert_eq!(shanten(&parse_hand("123m456p789s1122z").unwrap()), Ok(0));
+/// assert_eq!(shanten(&parse_hand("123m456p789s11222z").unwrap()), Ok(-1));
+/// assert_eq!(shanten(&parse_hand("19m19p19s1234567z").unwrap()), Ok(0));
+/// ```
+pub fn shanten(tiles: &[Tile]) -> Result<i8, HandError> {
+    let counts = count(tiles)?;
+    let size = counts.iter().map(|count| *count as usize).sum::<usize>();
+    let standard = standard_shanten(&counts, (size / 3) as i8);
+
+    Ok(if size >= 13 {
+        standard
+            .min(seven_pairs_shanten(&counts))
+            .min(thirteen_orphans_shanten(&counts))
+    } else {
+        standard
+    })
+}
+
+/// Returns the tiles that would complete a ready hand, in the order of [`Tile::id`].
+///
+/// The hand is as for [`shanten`], without the winning tile, so it has 13 tiles
+/// less 3 for each meld. Tiles of which the hand already holds all four copies are
+/// left out, as they cannot be drawn. The waits are empty if the hand is not ready.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::Tile;
+/// use mahjong_tiles::hand::{parse_hand, waits};
+///
+/// assert_eq!(
+///     waits(&parse_hand("345m456p789s11z67s").unwrap()),
+///     Ok(vec![Tile::TIAO(5), Tile::TIAO(8)])
+/// );
+/// ```
+pub fn waits(tiles: &[Tile]) -> Result<Vec<Tile>, HandError> {
+    let counts = count(tiles)?;
+    let size = counts.iter().map(|count| *count as usize).sum::<usize>();
+    if size % 3 != 1 {
+        return Err(HandError::InvalidSize(size));
+    }
+
+    let mut hand = tiles.to_vec();
+    Ok((0..HAND_KINDS as u8)
+        .filter_map(Tile::from_id)
+        .filter(|tile| counts[usize::from(tile.id())] < 4)
+        .filter(|tile| {
+            hand.push(*tile);
+            let complete = shanten(&hand) == Ok(-1);
+            hand.pop();
+            complete
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test_shanten {
+    use super::*;
+    use crate::hand::parse_hand;
+
+    macro_rules! create_test {
+        ($name:ident, $hand:literal, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(shanten(&parse_hand($hand).unwrap()), $expected);
+            }
+        };
+    }
+
+    create_test!(complete, "123m456p789s11222z", Ok(-1));
+    create_test!(ready, "123m456p789s1122z", Ok(0));
+    create_test!(one_away, "123m456p789s1134z", Ok(1));
+    create_test!(pairless_partials, "123456789m12p45p", Ok(1));
+    create_test!(isolated, "147m258p369s1234z", Ok(6));
+    create_test!(seven_pairs_ready, "1122m3344p5566s7z", Ok(0));
+    create_test!(seven_pairs_complete, "1122m3344p5566s77z", Ok(-1));
+    create_test!(seven_pairs_quad, "1111m3344p5566s77z", Ok(1));
+    create_test!(thirteen_orphans_ready, "19m19p19s1234567z", Ok(0));
+    create_test!(thirteen_orphans_complete, "19m19p19s12345677z", Ok(-1));
+    create_test!(with_melds, "123m45p", Ok(0));
+    create_test!(single, "5z", Ok(0));
+    create_test!(pair, "55z", Ok(-1));
+    create_test!(flowers_ignored, "123m456p789s1122z12f", Ok(0));
+    create_test!(
+        invalid_size,
+        "123m456p789s112z",
+        Err(HandError::InvalidSize(12))
+    );
+    create_test!(
+        too_many,
+        "11111m",
+        Err(HandError::TooManyCopies(Tile::WAN(1)))
+    );
+
+    #[test]
+    fn invalid_tile() {
+        assert_eq!(
+            shanten(&[Tile::TONG(10)]),
+            Err(HandError::InvalidTile(Tile::TONG(10)))
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_waits {
+    use super::*;
+    use crate::hand::parse_hand;
+
+    macro_rules! create_test {
+        ($name:ident, $hand:literal, $expected:literal) => {
+            #[test]
+            fn $name() {
+                assert_eq!(
+                    waits(&parse_hand($hand).unwrap()),
+                    Ok(parse_hand($expected).unwrap())
+                );
+            }
+        };
+    }
+
+    create_test!(nine_gates, "1112345678999m", "123456789m");
+    create_test!(two_sided, "123m456p789s23m11z", "14m");
+    create_test!(closed, "123m456p789s13m11z", "2m");
+    create_test!(pair_wait, "123m456p789s1113z", "3z");
+    create_test!(double_pair, "123m456p789s1133z", "13z");
+    create_test!(seven_pairs, "1122m3344p5566s7z", "7z");
+    create_test!(thirteen_sided, "19m19p19s1234567z", "19m19p19s1234567z");
+    create_test!(not_ready, "147m258p369s1234z", "");
+    create_test!(with_melds, "23p55z", "14p");
+    create_test!(all_copies_held, "123m456p789s1111z", "");
+
+    #[test]
+    fn complete_hand() {
+        assert_eq!(
+            waits(&parse_hand("123m456p789s11222z").unwrap()),
+            Err(HandError::InvalidSize(14))
+        );
+    }
+}
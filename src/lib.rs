@@ -9,7 +9,7 @@
 //!
 //! assert_eq!(tile.category(), TileCategory::TONG);
 //! assert_eq!(tile.value(), Some(1));
-//! assert_eq!(tile.unique_name(), "一筒");
+//! assert_eq!(tile.unique_name().as_deref(), Some("一筒"));
 //! assert_eq!(tile + 1, Some(Tile::TONG(2)));
 //!
 //! let svg = tile.svg_data(mahjong_tiles::SvgStyle::DARK).unwrap();
@@ -19,16 +19,53 @@
 //!
 //! assert_eq!(tile.category(), TileCategory::WAN);
 //! assert_eq!(tile.value(), Some(9));
-//! assert_eq!(tile.unique_name(), "九万");
+//! assert_eq!(tile.unique_name().as_deref(), Some("九万"));
 //! assert_eq!(tile - 1, Some(Tile::WAN(8)));
 //!
 //! let tile = Tile::EAST;
 //!
 //! assert_eq!(tile.category(), TileCategory::HONOUR(HonourCategory::WIND));
 //! ```
+//!
+//! # Features
+//!
+//! Without the default `std` feature the crate is `no_std`: [`Tile`], [`TileCategory`],
+//! tile arithmetic and hashing only need `core`, and names are `&'static str`s from
+//! `const fn`s such as [`Tile::svg_name`] and [`Tile::chinese_name`]. The `alloc`
+//! feature adds the [`hand`] module and `String` names such as [`IsTile::unique_name`].
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod hand;
 mod models;
+#[cfg(feature = "std")]
+pub mod record;
+#[cfg(feature = "std")]
+pub mod render;
+#[cfg(feature = "std")]
 mod svg;
+#[cfg(feature = "std")]
+pub mod theme;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "mjai")]
+pub mod mjai;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "server")]
+pub mod server;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use models::*;
-pub use svg::SvgStyle;
+#[cfg(feature = "std")]
+pub use svg::{BaseLayer, SvgStyle};
@@ -0,0 +1,170 @@
+//! Relays MJAI messages between a simulator and a bot.
+
+use std::io::{BufRead, Write};
+
+use super::{MjaiError, MjaiMessage};
+
+/// A bot that can play through the MJAI protocol.
+pub trait MjaiBot {
+    /// React to an event from the simulator.
+    ///
+    /// Returning [`None`] replies with [`MjaiMessage::None`], i.e. passing.
+    fn react(&mut self, event: &MjaiMessage) -> Option<MjaiMessage>;
+}
+
+/// Connects a [`MjaiBot`] to a simulator speaking MJAI over a pair of streams.
+///
+/// Every line received is answered with exactly one line. When a line carries a batch
+/// of events, the bot sees each of them in order and the last action it returns is
+/// sent back. Messages of types this crate does not know, [`MjaiMessage::Unknown`],
+/// are skipped.
+#[derive(Debug)]
+pub struct MjaiAdapter<B: MjaiBot> {
+    bot: B,
+}
+
+impl<B: MjaiBot> MjaiAdapter<B> {
+    /// Create a new adapter around a bot.
+    pub fn new(bot: B) -> Self {
+        Self { bot }
+    }
+
+    /// Get a reference to the bot.
+    pub fn bot(&self) -> &B {
+        &self.bot
+    }
+
+    /// Consume the adapter and return the bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+
+    /// Feed a line from the simulator to the bot, and get its reply.
+    pub fn handle_line(&mut self, line: &str) -> Result<MjaiMessage, MjaiError> {
+        Ok(self.handle_events(&MjaiMessage::from_json_line(line)?))
+    }
+
+    /// Feed a batch of events to the bot, and get its reply.
+    pub fn handle_events(&mut self, events: &[MjaiMessage]) -> MjaiMessage {
+        events
+            .iter()
+            .filter(|event| **event != MjaiMessage::Unknown)
+            .filter_map(|event| self.bot.react(event))
+            .last()
+            .unwrap_or(MjaiMessage::None)
+    }
+
+    /// Relay messages until the game ends or the simulator closes the stream.
+    pub fn run<R: BufRead, W: Write>(&mut self, reader: R, mut writer: W) -> Result<(), MjaiError> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let events = MjaiMessage::from_json_line(&line)?;
+            let is_end = events.contains(&MjaiMessage::EndGame);
+            let reply = self.handle_events(&events);
+            writeln!(writer, "{}", reply.to_json()?)?;
+            writer.flush()?;
+
+            if is_end {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Relay messages over the standard input and output of this process.
+    pub fn run_stdio(&mut self) -> Result<(), MjaiError> {
+        self.run(std::io::stdin().lock(), std::io::stdout().lock())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mjai::MjaiTile;
+
+    /// Discards whatever it draws.
+    struct Tsumogiri {
+        seat: u8,
+    }
+
+    impl MjaiBot for Tsumogiri {
+        fn react(&mut self, event: &MjaiMessage) -> Option<MjaiMessage> {
+            match event {
+                MjaiMessage::Hello { .. } => Some(MjaiMessage::Join {
+                    name: "tsumogiri".to_string(),
+                    room: None,
+                }),
+                MjaiMessage::StartGame { id: Some(id), .. } => {
+                    self.seat = *id;
+                    None
+                }
+                MjaiMessage::Tsumo { actor, pai } if *actor == self.seat => {
+                    Some(MjaiMessage::Dahai {
+                        actor: self.seat,
+                        pai: *pai,
+                        tsumogiri: true,
+                    })
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn relay() {
+        let input = [
+            r#"{"type":"hello","protocol":"mjsonp","protocol_version":3}"#,
+            r#"{"type":"start_game","id":2,"names":["a","b","c","d"]}"#,
+            "",
+            r#"{"type":"tsumo","actor":1,"pai":"?"}"#,
+            r#"{"type":"extension","actor":2}"#,
+            r#"[{"type":"dahai","actor":1,"pai":"E","tsumogiri":true},{"type":"tsumo","actor":2,"pai":"5sr"}]"#,
+            r#"{"type":"end_game"}"#,
+            r#"{"type":"tsumo","actor":2,"pai":"1m"}"#,
+        ]
+        .join("\n");
+        let mut output = Vec::new();
+
+        let mut adapter = MjaiAdapter::new(Tsumogiri { seat: 0 });
+        adapter.run(input.as_bytes(), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            [
+                r#"{"type":"join","name":"tsumogiri"}"#,
+                r#"{"type":"none"}"#,
+                r#"{"type":"none"}"#,
+                r#"{"type":"none"}"#,
+                r#"{"type":"dahai","actor":2,"pai":"5sr","tsumogiri":true}"#,
+                r#"{"type":"none"}"#,
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(adapter.into_inner().seat, 2);
+    }
+
+    #[test]
+    fn invalid_line() {
+        let mut adapter = MjaiAdapter::new(Tsumogiri { seat: 0 });
+        assert!(matches!(
+            adapter.handle_line("not json"),
+            Err(MjaiError::Json(_))
+        ));
+        assert_eq!(
+            adapter
+                .handle_line(r#"{"type":"tsumo","actor":0,"pai":"?"}"#)
+                .unwrap(),
+            MjaiMessage::Dahai {
+                actor: 0,
+                pai: MjaiTile::UNKNOWN,
+                tsumogiri: true,
+            }
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Errors raised while speaking the MJAI protocol.
+
+use crate::models::Tile;
+
+/// Errors raised while encoding, decoding or relaying MJAI messages.
+#[derive(Debug)]
+pub enum MjaiError {
+    /// The tile notation is not a valid MJAI tile.
+    InvalidTile(String),
+    /// The tile has no MJAI representation, e.g. flowers and seasons.
+    UnsupportedTile(Tile),
+    /// The message is not valid MJAI JSON.
+    Json(serde_json::Error),
+    /// The underlying stream failed.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for MjaiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTile(notation) => write!(f, "{notation:?} is not a valid MJAI tile."),
+            Self::UnsupportedTile(tile) => write!(f, "{tile:?} cannot be represented in MJAI."),
+            Self::Json(err) => write!(f, "Invalid MJAI message: {err}"),
+            Self::Io(err) => write!(f, "MJAI stream error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for MjaiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for MjaiError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<std::io::Error> for MjaiError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
@@ -0,0 +1,339 @@
+//! The MJAI message types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{MjaiError, MjaiTile};
+
+/// A single MJAI message, sent either by the simulator or by a bot.
+///
+/// Fields that only the simulator fills in, such as score deltas, are optional so that
+/// the same type can be used for both directions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MjaiMessage {
+    /// Greeting from the simulator.
+    Hello {
+        /// The name of the protocol.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        protocol: Option<String>,
+        /// The version of the protocol.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        protocol_version: Option<u32>,
+    },
+    /// Reply to [`MjaiMessage::Hello`] with the name of the bot.
+    Join {
+        /// The name of the bot.
+        name: String,
+        /// The room to join.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        room: Option<String>,
+    },
+    /// Start of a game.
+    StartGame {
+        /// The seat of the receiving bot.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<u8>,
+        /// The names of the players, in seat order.
+        #[serde(default)]
+        names: Vec<String>,
+    },
+    /// Start of a round, with the starting hands.
+    StartKyoku {
+        /// The prevailing wind.
+        bakaze: MjaiTile,
+        /// The round number within the prevailing wind, starting from 1.
+        kyoku: u8,
+        /// The number of repeat counters.
+        honba: u8,
+        /// The number of riichi sticks on the table.
+        kyotaku: u8,
+        /// The seat of the dealer.
+        oya: u8,
+        /// The first dora indicator.
+        dora_marker: MjaiTile,
+        /// The starting hands, in seat order; other players' tiles are concealed.
+        tehais: Vec<Vec<MjaiTile>>,
+    },
+    /// A player draws a tile.
+    Tsumo {
+        /// The seat of the player.
+        actor: u8,
+        /// The tile drawn; concealed for other players.
+        pai: MjaiTile,
+    },
+    /// A player discards a tile.
+    Dahai {
+        /// The seat of the player.
+        actor: u8,
+        /// The tile discarded.
+        pai: MjaiTile,
+        /// Whether the discarded tile is the one just drawn.
+        tsumogiri: bool,
+    },
+    /// A player claims a discard to form a triplet.
+    Pon {
+        /// The seat of the claiming player.
+        actor: u8,
+        /// The seat of the player who discarded.
+        target: u8,
+        /// The claimed tile.
+        pai: MjaiTile,
+        /// The tiles taken from the claiming player's hand.
+        consumed: Vec<MjaiTile>,
+    },
+    /// A player claims a discard to form a sequence.
+    Chi {
+        /// The seat of the claiming player.
+        actor: u8,
+        /// The seat of the player who discarded.
+        target: u8,
+        /// The claimed tile.
+        pai: MjaiTile,
+        /// The tiles taken from the claiming player's hand.
+        consumed: Vec<MjaiTile>,
+    },
+    /// A player claims a discard to form an exposed kong.
+    Daiminkan {
+        /// The seat of the claiming player.
+        actor: u8,
+        /// The seat of the player who discarded.
+        target: u8,
+        /// The claimed tile.
+        pai: MjaiTile,
+        /// The tiles taken from the claiming player's hand.
+        consumed: Vec<MjaiTile>,
+    },
+    /// A player declares a concealed kong.
+    Ankan {
+        /// The seat of the player.
+        actor: u8,
+        /// The four tiles of the kong.
+        consumed: Vec<MjaiTile>,
+    },
+    /// A player adds a tile to an exposed triplet.
+    Kakan {
+        /// The seat of the player.
+        actor: u8,
+        /// The added tile.
+        pai: MjaiTile,
+        /// The tiles of the existing triplet.
+        consumed: Vec<MjaiTile>,
+    },
+    /// A new dora indicator is revealed.
+    Dora {
+        /// The new dora indicator.
+        dora_marker: MjaiTile,
+    },
+    /// A player declares riichi.
+    Reach {
+        /// The seat of the player.
+        actor: u8,
+    },
+    /// The riichi declaration is accepted.
+    ReachAccepted {
+        /// The seat of the player.
+        actor: u8,
+        /// The score changes of each seat.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        deltas: Option<Vec<i32>>,
+        /// The scores of each seat afterwards.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scores: Option<Vec<i32>>,
+    },
+    /// A player wins.
+    Hora {
+        /// The seat of the winning player.
+        actor: u8,
+        /// The seat of the player who dealt in; equal to `actor` for a self-drawn win.
+        target: u8,
+        /// The winning tile.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pai: Option<MjaiTile>,
+        /// The score changes of each seat.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        deltas: Option<Vec<i32>>,
+        /// The scores of each seat afterwards.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scores: Option<Vec<i32>>,
+    },
+    /// The round ends in a draw.
+    Ryukyoku {
+        /// The reason for the draw.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+        /// The score changes of each seat.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        deltas: Option<Vec<i32>>,
+        /// The scores of each seat afterwards.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scores: Option<Vec<i32>>,
+    },
+    /// End of a round.
+    EndKyoku,
+    /// End of the game.
+    EndGame,
+    /// No action; the reply when a bot passes.
+    None,
+    /// An error reported by the simulator.
+    Error {
+        /// The error message.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// A message of a type this crate does not know, such as a simulator extension.
+    ///
+    /// Its fields are dropped, and it cannot be encoded.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl MjaiMessage {
+    /// Decode a single MJAI message.
+    pub fn from_json(json: &str) -> Result<Self, MjaiError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Decode a line from the simulator, which is either a single message or an array
+    /// of messages.
+    pub fn from_json_line(line: &str) -> Result<Vec<Self>, MjaiError> {
+        if line.trim_start().starts_with('[') {
+            Ok(serde_json::from_str(line)?)
+        } else {
+            Self::from_json(line).map(|message| vec![message])
+        }
+    }
+
+    /// Encode this message as a single line of JSON.
+    pub fn to_json(&self) -> Result<String, MjaiError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+#[cfg(test)]
+mod test_roundtrip {
+    use super::*;
+    use crate::models::Tile;
+
+    fn known(tile: Tile) -> MjaiTile {
+        MjaiTile::try_from(tile).unwrap()
+    }
+
+    macro_rules! create_test {
+        ($name:ident, $json:literal, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let message = MjaiMessage::from_json($json).unwrap();
+                assert_eq!(message, $expected);
+                assert_eq!(message.to_json().unwrap(), $json);
+            }
+        };
+    }
+
+    create_test!(
+        start_game,
+        r#"{"type":"start_game","id":1,"names":["a","b","c","d"]}"#,
+        MjaiMessage::StartGame {
+            id: Some(1),
+            names: ["a", "b", "c", "d"].map(String::from).to_vec(),
+        }
+    );
+    create_test!(
+        tsumo_concealed,
+        r#"{"type":"tsumo","actor":2,"pai":"?"}"#,
+        MjaiMessage::Tsumo {
+            actor: 2,
+            pai: MjaiTile::UNKNOWN,
+        }
+    );
+    create_test!(
+        dahai_red,
+        r#"{"type":"dahai","actor":0,"pai":"5pr","tsumogiri":false}"#,
+        MjaiMessage::Dahai {
+            actor: 0,
+            pai: MjaiTile::red(Tile::TONG(5)).unwrap(),
+            tsumogiri: false,
+        }
+    );
+    create_test!(
+        pon,
+        r#"{"type":"pon","actor":1,"target":0,"pai":"C","consumed":["C","C"]}"#,
+        MjaiMessage::Pon {
+            actor: 1,
+            target: 0,
+            pai: known(Tile::CENTRAL),
+            consumed: vec![known(Tile::CENTRAL); 2],
+        }
+    );
+    create_test!(
+        chi,
+        r#"{"type":"chi","actor":1,"target":0,"pai":"3s","consumed":["4s","5s"]}"#,
+        MjaiMessage::Chi {
+            actor: 1,
+            target: 0,
+            pai: known(Tile::TIAO(3)),
+            consumed: vec![known(Tile::TIAO(4)), known(Tile::TIAO(5))],
+        }
+    );
+    create_test!(
+        ankan,
+        r#"{"type":"ankan","actor":3,"consumed":["E","E","E","E"]}"#,
+        MjaiMessage::Ankan {
+            actor: 3,
+            consumed: vec![known(Tile::EAST); 4],
+        }
+    );
+    create_test!(
+        reach,
+        r#"{"type":"reach","actor":0}"#,
+        MjaiMessage::Reach { actor: 0 }
+    );
+    create_test!(
+        hora,
+        r#"{"type":"hora","actor":1,"target":0,"pai":"9m"}"#,
+        MjaiMessage::Hora {
+            actor: 1,
+            target: 0,
+            pai: Some(known(Tile::WAN(9))),
+            deltas: None,
+            scores: None,
+        }
+    );
+    create_test!(
+        ryukyoku,
+        r#"{"type":"ryukyoku","reason":"fanpai"}"#,
+        MjaiMessage::Ryukyoku {
+            reason: Some("fanpai".to_string()),
+            deltas: None,
+            scores: None,
+        }
+    );
+    create_test!(none, r#"{"type":"none"}"#, MjaiMessage::None);
+
+    #[test]
+    fn batch_line() {
+        let messages =
+            MjaiMessage::from_json_line(r#"[{"type":"reach","actor":0},{"type":"end_kyoku"}]"#)
+                .unwrap();
+        assert_eq!(
+            messages,
+            vec![MjaiMessage::Reach { actor: 0 }, MjaiMessage::EndKyoku]
+        );
+    }
+
+    #[test]
+    fn invalid_tile() {
+        assert!(matches!(
+            MjaiMessage::from_json(r#"{"type":"tsumo","actor":0,"pai":"0m"}"#),
+            Err(MjaiError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_type() {
+        assert_eq!(
+            MjaiMessage::from_json(r#"{"type":"extension","actor":0,"detail":[1,2]}"#).unwrap(),
+            MjaiMessage::Unknown
+        );
+        assert!(MjaiMessage::Unknown.to_json().is_err());
+    }
+}
@@ -0,0 +1,31 @@
+//! Adapter for the [MJAI](https://github.com/gimite/mjai) JSON-lines protocol.
+//!
+//! Only available with the `mjai` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mahjong_tiles::Tile;
+//! use mahjong_tiles::mjai::{MjaiMessage, MjaiTile};
+//!
+//! let message = MjaiMessage::from_json(r#"{"type":"dahai","actor":0,"pai":"5mr","tsumogiri":true}"#).unwrap();
+//!
+//! if let MjaiMessage::Dahai { pai, .. } = &message {
+//!     assert_eq!(pai.tile(), Some(Tile::WAN(5)));
+//!     assert!(pai.is_red());
+//! }
+//!
+//! assert_eq!(MjaiTile::try_from(Tile::PROSPERITY).unwrap().to_string(), "F");
+//! ```
+
+mod adapter;
+pub use adapter::*;
+
+mod error;
+pub use error::*;
+
+mod message;
+pub use message::*;
+
+mod tile;
+pub use tile::*;
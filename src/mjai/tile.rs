@@ -0,0 +1,229 @@
+//! Conversion between [`Tile`] and the MJAI tile notation.
+//!
+//! MJAI writes number tiles as `<value><suit>` with `m` for [`Tile::WAN`], `p` for
+//! [`Tile::TONG`] and `s` for [`Tile::TIAO`]; red fives carry a trailing `r`. Honours
+//! are `E`, `S`, `W`, `N` for the winds and `P`, `F`, `C` for the Blank, Prosperity
+//! and Central dragons. Concealed tiles are written as `?`.
+
+use super::MjaiError;
+use crate::models::{Tile, TileCategory};
+
+/// A tile as written in MJAI messages.
+///
+/// Known tiles are created through [`TryFrom<Tile>`] or [`MjaiTile::red`], so every
+/// `MjaiTile` has an MJAI notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MjaiTile {
+    /// The kind of the tile, or [`None`] if it is concealed from the receiver.
+    tile: Option<Tile>,
+    /// Whether this is a red five.
+    red: bool,
+}
+
+impl MjaiTile {
+    /// A tile concealed from the receiver, written as `?`.
+    pub const UNKNOWN: Self = Self {
+        tile: None,
+        red: false,
+    };
+
+    /// Create a red five of the given number tile.
+    ///
+    /// Returns [`None`] if the tile is not a five.
+    pub fn red(tile: Tile) -> Option<Self> {
+        if tile.is_number() && tile.value() == Some(5) {
+            Some(Self {
+                tile: Some(tile),
+                red: true,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the kind of the tile, if it is known.
+    pub fn tile(&self) -> Option<Tile> {
+        self.tile
+    }
+
+    /// Returns whether this is a red five.
+    pub fn is_red(&self) -> bool {
+        self.red
+    }
+}
+
+impl TryFrom<Tile> for MjaiTile {
+    type Error = MjaiError;
+
+    /// Convert a [`Tile`] into its regular, non-red, MJAI form.
+    ///
+    /// Flowers, seasons and tiles out of range have no MJAI form.
+    fn try_from(tile: Tile) -> Result<Self, Self::Error> {
+        if tile.is_flower() || tile.checked_id().is_none() {
+            Err(MjaiError::UnsupportedTile(tile))
+        } else {
+            Ok(Self {
+                tile: Some(tile),
+                red: false,
+            })
+        }
+    }
+}
+
+impl std::fmt::Display for MjaiTile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(tile) = self.tile else {
+            return write!(f, "?");
+        };
+        let red = if self.red { "r" } else { "" };
+
+        match tile {
+            Tile::EAST => write!(f, "E"),
+            Tile::SOUTH => write!(f, "S"),
+            Tile::WEST => write!(f, "W"),
+            Tile::NORTH => write!(f, "N"),
+            Tile::BLANK => write!(f, "P"),
+            Tile::PROSPERITY => write!(f, "F"),
+            Tile::CENTRAL => write!(f, "C"),
+            Tile::WAN(v) => write!(f, "{v}m{red}"),
+            Tile::TONG(v) => write!(f, "{v}p{red}"),
+            Tile::TIAO(v) => write!(f, "{v}s{red}"),
+            _ => unreachable!("MjaiTile should not hold a flower or season."),
+        }
+    }
+}
+
+impl std::str::FromStr for MjaiTile {
+    type Err = MjaiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || MjaiError::InvalidTile(s.to_string());
+
+        let tile = match s {
+            "?" => return Ok(Self::UNKNOWN),
+            "E" => Tile::EAST,
+            "S" => Tile::SOUTH,
+            "W" => Tile::WEST,
+            "N" => Tile::NORTH,
+            "P" => Tile::BLANK,
+            "F" => Tile::PROSPERITY,
+            "C" => Tile::CENTRAL,
+            _ => {
+                let (notation, red) = match s.strip_suffix('r') {
+                    Some(notation) => (notation, true),
+                    None => (s, false),
+                };
+                let mut chars = notation.chars();
+                let value = chars
+                    .next()
+                    .and_then(|c| c.to_digit(10))
+                    .filter(|v| (1..=9).contains(v))
+                    .ok_or_else(invalid)? as u8;
+                let category = match (chars.next(), chars.next()) {
+                    (Some('m'), None) => TileCategory::WAN,
+                    (Some('p'), None) => TileCategory::TONG,
+                    (Some('s'), None) => TileCategory::TIAO,
+                    _ => return Err(invalid()),
+                };
+                let tile = Tile::new_valued(category, value);
+
+                return if red {
+                    Self::red(tile).ok_or_else(invalid)
+                } else {
+                    Self::try_from(tile).map_err(|_| invalid())
+                };
+            }
+        };
+
+        Self::try_from(tile).map_err(|_| invalid())
+    }
+}
+
+impl serde::Serialize for MjaiTile {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MjaiTile {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let notation = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        notation.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test_notation {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $tile:expr, $notation:literal) => {
+            #[test]
+            fn $name() {
+                assert_eq!($tile.to_string(), $notation);
+                assert_eq!($notation.parse::<MjaiTile>().unwrap(), $tile);
+            }
+        };
+    }
+
+    fn known(tile: Tile) -> MjaiTile {
+        MjaiTile::try_from(tile).unwrap()
+    }
+
+    create_test!(wan1, known(Tile::WAN(1)), "1m");
+    create_test!(tong5, known(Tile::TONG(5)), "5p");
+    create_test!(tiao9, known(Tile::TIAO(9)), "9s");
+    create_test!(wan5_red, MjaiTile::red(Tile::WAN(5)).unwrap(), "5mr");
+    create_test!(tong5_red, MjaiTile::red(Tile::TONG(5)).unwrap(), "5pr");
+    create_test!(tiao5_red, MjaiTile::red(Tile::TIAO(5)).unwrap(), "5sr");
+    create_test!(east, known(Tile::EAST), "E");
+    create_test!(south, known(Tile::SOUTH), "S");
+    create_test!(west, known(Tile::WEST), "W");
+    create_test!(north, known(Tile::NORTH), "N");
+    create_test!(blank, known(Tile::BLANK), "P");
+    create_test!(prosperity, known(Tile::PROSPERITY), "F");
+    create_test!(central, known(Tile::CENTRAL), "C");
+    create_test!(unknown, MjaiTile::UNKNOWN, "?");
+}
+
+#[cfg(test)]
+mod test_invalid {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $notation:literal) => {
+            #[test]
+            fn $name() {
+                assert!(matches!(
+                    $notation.parse::<MjaiTile>(),
+                    Err(MjaiError::InvalidTile(_))
+                ));
+            }
+        };
+    }
+
+    create_test!(empty, "");
+    create_test!(zero, "0m");
+    create_test!(bad_suit, "5z");
+    create_test!(red_non_five, "4mr");
+    create_test!(red_honour, "Er");
+    create_test!(trailing, "5mm");
+    create_test!(lowercase_honour, "e");
+
+    #[test]
+    fn flower_unsupported() {
+        assert!(matches!(
+            MjaiTile::try_from(Tile::PLUM),
+            Err(MjaiError::UnsupportedTile(Tile::PLUM))
+        ));
+    }
+
+    #[test]
+    fn out_of_range_unsupported() {
+        assert!(matches!(
+            MjaiTile::try_from(Tile::TONG(10)),
+            Err(MjaiError::UnsupportedTile(Tile::TONG(10)))
+        ));
+        assert_eq!(MjaiTile::red(Tile::EAST), None);
+    }
+}
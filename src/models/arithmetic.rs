@@ -1,23 +1,41 @@
 //! Implementation of basic arithmetic operations on [`Tile`].
 //!
 
-use std::ops::{Add, Sub};
+use core::ops::{Add, Sub};
 
-use crate::models::{IsTile, Tile};
+use crate::models::Tile;
+
+impl Tile {
+    /// Returns the tile `other` places higher in the same suit, or [`None`] if it
+    /// would pass 9 or this is not a number tile; the `const` form of `tile + other`.
+    pub const fn checked_add(self, other: u8) -> Option<Self> {
+        match self.value() {
+            Some(value @ 1..=9) => match value.checked_add(other) {
+                Some(value @ 1..=9) => Some(Tile::new_valued(self.category(), value)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the tile `other` places lower in the same suit, or [`None`] if it
+    /// would pass 1 or this is not a number tile; the `const` form of `tile - other`.
+    pub const fn checked_sub(self, other: u8) -> Option<Self> {
+        match self.value() {
+            Some(value @ 1..=9) => match value.checked_sub(other) {
+                Some(value @ 1..=9) => Some(Tile::new_valued(self.category(), value)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
 
 impl Add<u8> for Tile {
     type Output = Option<Self>;
 
     fn add(self, other: u8) -> Self::Output {
-        self.value()
-            .and_then(|value| value.checked_add(other))
-            .and_then(|new_value| {
-                if new_value > 9 {
-                    None
-                } else {
-                    Some(Tile::new_valued(self.category(), new_value))
-                }
-            })
+        self.checked_add(other)
     }
 }
 
@@ -25,15 +43,7 @@ impl Sub<u8> for Tile {
     type Output = Option<Self>;
 
     fn sub(self, other: u8) -> Self::Output {
-        self.value()
-            .and_then(|value| value.checked_sub(other))
-            .and_then(|new_value| {
-                if new_value == 0 {
-                    None
-                } else {
-                    Some(Tile::new_valued(self.category(), new_value))
-                }
-            })
+        self.checked_sub(other)
     }
 }
 
@@ -73,6 +83,9 @@ mod test_add {
     create_test!(central_add_1, Tile::CENTRAL, 1, None);
     create_test!(spring_add_1, Tile::SPRING, 1, None);
     create_test!(tong9_add_255, Tile::TONG(9), 255, None);
+    create_test!(tong12_add_0, Tile::TONG(12), 0, None);
+    create_test!(wan0_add_1, Tile::WAN(0), 1, None);
+    create_test!(tiao255_add_255, Tile::TIAO(255), 255, None);
 }
 
 #[cfg(test)]
@@ -114,4 +127,7 @@ mod test_sub {
     create_test!(central_sub_1, Tile::CENTRAL, 1, None);
     create_test!(spring_sub_1, Tile::SPRING, 1, None);
     create_test!(tong9_sub_255, Tile::TONG(9), 255, None);
+    create_test!(tong12_sub_1, Tile::TONG(12), 1, None);
+    create_test!(tong12_sub_3, Tile::TONG(12), 3, None);
+    create_test!(wan0_sub_0, Tile::WAN(0), 0, None);
 }
@@ -1,5 +1,6 @@
 /// The sub-categories for Mahjong honours.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HonourCategory {
     /// The four winds: East, South, West, North.
     WIND,
@@ -9,6 +10,7 @@ pub enum HonourCategory {
 
 /// The sub-categories for Flower tiles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FlowerCategory {
     /// The four seasons: Spring, Summer, Autumn, Winter.
     SEASON,
@@ -18,6 +20,7 @@ pub enum FlowerCategory {
 
 /// The various categories of Mahjong tiles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TileCategory {
     /// The 4 seasons and 4 flowers.
     FLOWER(FlowerCategory),
@@ -33,7 +36,7 @@ pub enum TileCategory {
 
 impl TileCategory {
     /// Get the name of the category in Chinese.
-    pub fn category_name_chinese(&self) -> &'static str {
+    pub const fn category_name_chinese(&self) -> &'static str {
         match self {
             TileCategory::FLOWER(FlowerCategory::FLOWER) => "花",
             TileCategory::FLOWER(FlowerCategory::SEASON) => "季",
@@ -45,7 +48,7 @@ impl TileCategory {
         }
     }
     /// Get the name of the category.
-    pub fn category_name(&self) -> &'static str {
+    pub const fn category_name(&self) -> &'static str {
         match self {
             TileCategory::FLOWER(FlowerCategory::FLOWER) => "flower",
             TileCategory::FLOWER(FlowerCategory::SEASON) => "season",
@@ -1,10 +1,10 @@
 //! Implementation of hashing operations on [`Tile`].
 //!
 
-use crate::models::{FlowerCategory, HonourCategory, IsTile, Tile, TileCategory};
+use crate::models::{FlowerCategory, HonourCategory, Tile, TileCategory};
 
-impl std::hash::Hash for TileCategory {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for TileCategory {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         let id: u8 = match self {
             Self::HONOUR(HonourCategory::WIND) => 0b00,
             Self::HONOUR(HonourCategory::DRAGON) => 0b01,
@@ -19,9 +19,9 @@ impl std::hash::Hash for TileCategory {
     }
 }
 
-impl std::hash::Hash for Tile {
+impl core::hash::Hash for Tile {
     /// Hash the tile into a unique identifier.
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         let category = self.category();
         category.hash(state);
         match &category {
@@ -0,0 +1,318 @@
+//! Names of tiles and categories in other languages.
+//!
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use crate::models::{FlowerCategory, HonourCategory, TileCategory};
+#[cfg(feature = "alloc")]
+use crate::models::{HasChineseValue, Tile};
+
+/// A language, or writing system, for naming tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// Simplified Chinese, as returned by
+    /// [`IsTile::unique_name`](crate::IsTile::unique_name), e.g. `一筒`, `发`.
+    SIMPLIFIED,
+    /// Traditional Chinese, e.g. `一萬`, `發`.
+    TRADITIONAL,
+    /// Japanese, with the suits in katakana, e.g. `1ピン`, `東`, `白`.
+    JAPANESE,
+    /// English, e.g. `1 Circle`, `East Wind`.
+    ENGLISH,
+    /// Cantonese in Jyutping, with tone numbers, e.g. `jat1 tung4`, `dung1 fung1`.
+    CANTONESE,
+    /// Riichi terms in romaji, e.g. `ipin`, `ton`.
+    ROMAJI,
+}
+
+impl TileCategory {
+    /// Get the name of the category in the given locale.
+    pub const fn localised_name(&self, locale: Locale) -> &'static str {
+        use FlowerCategory::{FLOWER, SEASON};
+        use HonourCategory::{DRAGON, WIND};
+
+        match locale {
+            Locale::SIMPLIFIED => self.category_name_chinese(),
+            Locale::TRADITIONAL => match self {
+                TileCategory::FLOWER(FLOWER) => "花",
+                TileCategory::FLOWER(SEASON) => "季",
+                TileCategory::HONOUR(WIND) => "四風",
+                TileCategory::HONOUR(DRAGON) => "三元",
+                TileCategory::TONG => "筒",
+                TileCategory::WAN => "萬",
+                TileCategory::TIAO => "條",
+            },
+            Locale::JAPANESE => match self {
+                TileCategory::FLOWER(FLOWER) => "花牌",
+                TileCategory::FLOWER(SEASON) => "季節牌",
+                TileCategory::HONOUR(WIND) => "風牌",
+                TileCategory::HONOUR(DRAGON) => "三元牌",
+                TileCategory::TONG => "筒子",
+                TileCategory::WAN => "萬子",
+                TileCategory::TIAO => "索子",
+            },
+            Locale::ENGLISH => match self {
+                TileCategory::FLOWER(FLOWER) => "Flowers",
+                TileCategory::FLOWER(SEASON) => "Seasons",
+                TileCategory::HONOUR(WIND) => "Winds",
+                TileCategory::HONOUR(DRAGON) => "Dragons",
+                TileCategory::TONG => "Circles",
+                TileCategory::WAN => "Characters",
+                TileCategory::TIAO => "Bamboos",
+            },
+            Locale::CANTONESE => match self {
+                TileCategory::FLOWER(FLOWER) => "faa1",
+                TileCategory::FLOWER(SEASON) => "gwai3",
+                TileCategory::HONOUR(WIND) => "fung1",
+                TileCategory::HONOUR(DRAGON) => "saam1 jyun4",
+                TileCategory::TONG => "tung4",
+                TileCategory::WAN => "maan6",
+                TileCategory::TIAO => "sok3",
+            },
+            Locale::ROMAJI => match self {
+                TileCategory::FLOWER(FLOWER) => "hana",
+                TileCategory::FLOWER(SEASON) => "kisetsu",
+                TileCategory::HONOUR(WIND) => "kazehai",
+                TileCategory::HONOUR(DRAGON) => "sangenpai",
+                TileCategory::TONG => "pinzu",
+                TileCategory::WAN => "manzu",
+                TileCategory::TIAO => "souzu",
+            },
+        }
+    }
+}
+
+/// Get the name of a number tile in the given locale.
+///
+/// The value must be in `1..=9`.
+#[cfg(feature = "alloc")]
+fn number_name(category: TileCategory, value: u8, locale: Locale) -> String {
+    let index = usize::from(value - 1);
+
+    match locale {
+        Locale::SIMPLIFIED | Locale::TRADITIONAL => {
+            format!(
+                "{}{}",
+                value.chinese_value(),
+                category.localised_name(locale)
+            )
+        }
+        Locale::JAPANESE => {
+            let suit = match category {
+                TileCategory::TONG => "ピン",
+                TileCategory::WAN => "マン",
+                _ => "ソー",
+            };
+            format!("{value}{suit}")
+        }
+        Locale::ENGLISH => {
+            let suit = match category {
+                TileCategory::TONG => "Circle",
+                TileCategory::WAN => "Character",
+                _ => "Bamboo",
+            };
+            format!("{value} {suit}")
+        }
+        Locale::CANTONESE => {
+            const NUMERALS: [&str; 9] = [
+                "jat1", "ji6", "saam1", "sei3", "ng5", "luk6", "cat1", "baat3", "gau2",
+            ];
+            format!("{} {}", NUMERALS[index], category.localised_name(locale))
+        }
+        Locale::ROMAJI => {
+            const NUMERALS: [&str; 9] = [
+                "i", "ryan", "san", "suu", "uu", "rou", "chii", "paa", "kyuu",
+            ];
+            let suit = match category {
+                TileCategory::TONG => "pin",
+                TileCategory::WAN => "man",
+                _ => "sou",
+            };
+            format!("{}{suit}", NUMERALS[index])
+        }
+    }
+}
+
+/// Get the name of a tile in the given locale, or [`None`] if it is not a valid tile;
+/// see [`IsTile::localised_name`](crate::IsTile::localised_name).
+#[cfg(feature = "alloc")]
+pub(crate) fn localised_tile_name(tile: &Tile, locale: Locale) -> Option<String> {
+    tile.checked_id()?;
+    if let (Locale::SIMPLIFIED, Some(name)) = (locale, tile.chinese_name()) {
+        return Some(name.to_string());
+    }
+    if let Some(value) = tile.value() {
+        return Some(number_name(tile.category(), value, locale));
+    }
+
+    // Simplified, Traditional, Japanese, English, Cantonese and Romaji.
+    let names: [&str; 6] = match tile {
+        Tile::EAST => ["东", "東", "東", "East Wind", "dung1 fung1", "ton"],
+        Tile::SOUTH => ["南", "南", "南", "South Wind", "naam4 fung1", "nan"],
+        Tile::WEST => ["西", "西", "西", "West Wind", "sai1 fung1", "shaa"],
+        Tile::NORTH => ["北", "北", "北", "North Wind", "bak1 fung1", "pei"],
+        Tile::CENTRAL => ["中", "中", "中", "Red Dragon", "hung4 zung1", "chun"],
+        Tile::PROSPERITY => ["发", "發", "發", "Green Dragon", "faat3 coi4", "hatsu"],
+        Tile::BLANK => ["白", "白", "白", "White Dragon", "baak6 baan2", "haku"],
+        Tile::PLUM => ["梅", "梅", "梅", "Plum", "mui4", "ume"],
+        Tile::ORCHID => ["兰", "蘭", "蘭", "Orchid", "laan4", "ran"],
+        Tile::CHRYSANTHEMUM => ["菊", "菊", "菊", "Chrysanthemum", "guk1", "kiku"],
+        Tile::BAMBOO => ["竹", "竹", "竹", "Bamboo", "zuk1", "take"],
+        Tile::SPRING => ["春", "春", "春", "Spring", "ceon1", "haru"],
+        Tile::SUMMER => ["夏", "夏", "夏", "Summer", "haa6", "natsu"],
+        Tile::AUTUMN => ["秋", "秋", "秋", "Autumn", "cau1", "aki"],
+        Tile::WINTER => ["冬", "冬", "冬", "Winter", "dung1", "fuyu"],
+        Tile::TONG(_) | Tile::WAN(_) | Tile::TIAO(_) => {
+            unreachable!("Unreachable: Number tiles should have a value")
+        }
+    };
+
+    let index = match locale {
+        Locale::SIMPLIFIED => 0,
+        Locale::TRADITIONAL => 1,
+        Locale::JAPANESE => 2,
+        Locale::ENGLISH => 3,
+        Locale::CANTONESE => 4,
+        Locale::ROMAJI => 5,
+    };
+    Some(names[index].to_string())
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test_tile_names {
+    use super::*;
+    use crate::models::IsTile;
+
+    macro_rules! create_test {
+        ($name:ident, $tile:expr, $locale:expr, $expected:literal) => {
+            #[test]
+            fn $name() {
+                assert_eq!($tile.localised_name($locale).as_deref(), Some($expected));
+            }
+        };
+    }
+
+    create_test!(tong1_simplified, Tile::TONG(1), Locale::SIMPLIFIED, "一筒");
+    create_test!(wan9_traditional, Tile::WAN(9), Locale::TRADITIONAL, "九萬");
+    create_test!(
+        tiao3_traditional,
+        Tile::TIAO(3),
+        Locale::TRADITIONAL,
+        "三條"
+    );
+    create_test!(tong1_japanese, Tile::TONG(1), Locale::JAPANESE, "1ピン");
+    create_test!(tiao5_japanese, Tile::TIAO(5), Locale::JAPANESE, "5ソー");
+    create_test!(tong1_english, Tile::TONG(1), Locale::ENGLISH, "1 Circle");
+    create_test!(wan7_english, Tile::WAN(7), Locale::ENGLISH, "7 Character");
+    create_test!(
+        tong5_cantonese,
+        Tile::TONG(5),
+        Locale::CANTONESE,
+        "ng5 tung4"
+    );
+    create_test!(tong1_romaji, Tile::TONG(1), Locale::ROMAJI, "ipin");
+    create_test!(wan2_romaji, Tile::WAN(2), Locale::ROMAJI, "ryanman");
+    create_test!(tiao9_romaji, Tile::TIAO(9), Locale::ROMAJI, "kyuusou");
+    create_test!(east_simplified, Tile::EAST, Locale::SIMPLIFIED, "东");
+    create_test!(east_traditional, Tile::EAST, Locale::TRADITIONAL, "東");
+    create_test!(east_english, Tile::EAST, Locale::ENGLISH, "East Wind");
+    create_test!(east_romaji, Tile::EAST, Locale::ROMAJI, "ton");
+    create_test!(
+        prosperity_traditional,
+        Tile::PROSPERITY,
+        Locale::TRADITIONAL,
+        "發"
+    );
+    create_test!(blank_japanese, Tile::BLANK, Locale::JAPANESE, "白");
+    create_test!(
+        central_cantonese,
+        Tile::CENTRAL,
+        Locale::CANTONESE,
+        "hung4 zung1"
+    );
+    create_test!(orchid_traditional, Tile::ORCHID, Locale::TRADITIONAL, "蘭");
+    create_test!(winter_english, Tile::WINTER, Locale::ENGLISH, "Winter");
+
+    #[test]
+    fn simplified_is_unique_name() {
+        (0..Tile::KINDS).filter_map(Tile::from_id).for_each(|tile| {
+            assert_eq!(tile.localised_name(Locale::SIMPLIFIED), tile.unique_name())
+        });
+    }
+
+    #[test]
+    fn out_of_range_has_no_name() {
+        for locale in [
+            Locale::SIMPLIFIED,
+            Locale::TRADITIONAL,
+            Locale::JAPANESE,
+            Locale::ENGLISH,
+            Locale::CANTONESE,
+            Locale::ROMAJI,
+        ] {
+            for tile in [Tile::WAN(0), Tile::TONG(10), Tile::TIAO(u8::MAX)] {
+                assert_eq!(tile.localised_name(locale), None, "{tile:?} in {locale:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn chinese_names_match_numerals() {
+        (0..Tile::KINDS)
+            .filter_map(Tile::from_id)
+            .filter_map(|tile| Some((tile, tile.value()?)))
+            .for_each(|(tile, value)| {
+                assert_eq!(
+                    Some(number_name(tile.category(), value, Locale::SIMPLIFIED).as_str()),
+                    tile.chinese_name()
+                )
+            });
+    }
+}
+
+#[cfg(test)]
+mod test_category_names {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $category:expr, $locale:expr, $expected:literal) => {
+            #[test]
+            fn $name() {
+                assert_eq!($category.localised_name($locale), $expected);
+            }
+        };
+    }
+
+    create_test!(wan_simplified, TileCategory::WAN, Locale::SIMPLIFIED, "万");
+    create_test!(
+        wan_traditional,
+        TileCategory::WAN,
+        Locale::TRADITIONAL,
+        "萬"
+    );
+    create_test!(tiao_japanese, TileCategory::TIAO, Locale::JAPANESE, "索子");
+    create_test!(tong_english, TileCategory::TONG, Locale::ENGLISH, "Circles");
+    create_test!(
+        season_english,
+        TileCategory::FLOWER(FlowerCategory::SEASON),
+        Locale::ENGLISH,
+        "Seasons"
+    );
+    create_test!(
+        wind_romaji,
+        TileCategory::HONOUR(HonourCategory::WIND),
+        Locale::ROMAJI,
+        "kazehai"
+    );
+    create_test!(
+        tiao_cantonese,
+        TileCategory::TIAO,
+        Locale::CANTONESE,
+        "sok3"
+    );
+}
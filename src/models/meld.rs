@@ -0,0 +1,329 @@
+use alloc::{vec, vec::Vec};
+
+use super::Tile;
+
+/// The kinds of kongs, by how they were formed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KongKind {
+    /// Formed by claiming a discard onto a concealed triplet.
+    EXPOSED,
+    /// Formed entirely from the player's own hand.
+    CONCEALED,
+    /// Formed by adding a drawn tile to an exposed triplet.
+    ADDED,
+}
+
+/// The kinds of melds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MeldKind {
+    /// Three consecutive number tiles of the same suit.
+    CHOW,
+    /// Three identical tiles.
+    PUNG,
+    /// Four identical tiles.
+    KONG(KongKind),
+}
+
+/// The discarded tile that was claimed to form a [`Meld`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Claim {
+    /// The tile claimed.
+    pub tile: Tile,
+    /// The seat of the player who discarded the tile.
+    pub from: u8,
+}
+
+/// A set of tiles declared by a player.
+///
+/// With the `serde` feature, melds are checked as by [`Meld::new`] when deserialised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedMeld"))]
+pub struct Meld {
+    kind: MeldKind,
+    tiles: Vec<Tile>,
+    claim: Option<Claim>,
+}
+
+/// The fields of a [`Meld`], as deserialised before they are checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedMeld {
+    kind: MeldKind,
+    tiles: Vec<Tile>,
+    claim: Option<Claim>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedMeld> for Meld {
+    type Error = &'static str;
+
+    fn try_from(meld: UncheckedMeld) -> Result<Self, Self::Error> {
+        Self::new(meld.kind, meld.tiles, meld.claim).ok_or("tiles do not form a valid meld")
+    }
+}
+
+impl Meld {
+    /// Create a new meld, checking that the tiles form the given kind.
+    ///
+    /// The tiles include the claimed tile, if any, and must all be valid; see
+    /// [`Tile::checked_id`]. Concealed kongs must not have a claim.
+    pub fn new(kind: MeldKind, tiles: Vec<Tile>, claim: Option<Claim>) -> Option<Self> {
+        let first = *tiles.first()?;
+        let is_valid = match kind {
+            MeldKind::CHOW => {
+                tiles.len() == 3 && first.is_number() && {
+                    let mut sorted = tiles.clone();
+                    sorted.sort_by_key(|tile| tile.value());
+                    sorted.windows(2).all(|pair| pair[0] + 1 == Some(pair[1]))
+                }
+            }
+            MeldKind::PUNG => tiles.len() == 3 && tiles.iter().all(|tile| *tile == first),
+            MeldKind::KONG(kong_kind) => {
+                tiles.len() == 4
+                    && tiles.iter().all(|tile| *tile == first)
+                    && (kong_kind == KongKind::CONCEALED) == claim.is_none()
+            }
+        } && !first.is_flower()
+            && tiles.iter().all(|tile| tile.checked_id().is_some())
+            && claim.is_none_or(|claim| tiles.contains(&claim.tile));
+
+        is_valid.then_some(Self { kind, tiles, claim })
+    }
+
+    /// Create a chow from three tiles, claimed from a player.
+    pub fn chow(tiles: [Tile; 3], claim: Claim) -> Option<Self> {
+        Self::new(MeldKind::CHOW, tiles.to_vec(), Some(claim))
+    }
+
+    /// Create a pung of a tile, claimed from a player.
+    pub fn pung(tile: Tile, from: u8) -> Option<Self> {
+        Self::new(MeldKind::PUNG, vec![tile; 3], Some(Claim { tile, from }))
+    }
+
+    /// Create a kong of a tile.
+    ///
+    /// `from` is the player the tile was originally claimed from; it is ignored for
+    /// concealed kongs.
+    pub fn kong(tile: Tile, kind: KongKind, from: u8) -> Option<Self> {
+        let claim = (kind != KongKind::CONCEALED).then_some(Claim { tile, from });
+        Self::new(MeldKind::KONG(kind), vec![tile; 4], claim)
+    }
+
+    /// Returns the kind of the meld.
+    pub fn kind(&self) -> MeldKind {
+        self.kind
+    }
+
+    /// Returns the tiles of the meld, including the claimed tile.
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    /// Returns the claimed discard, if the meld was formed by claiming.
+    pub fn claim(&self) -> Option<Claim> {
+        self.claim
+    }
+
+    /// Returns whether the meld is hidden from other players.
+    pub fn is_concealed(&self) -> bool {
+        self.kind == MeldKind::KONG(KongKind::CONCEALED)
+    }
+
+    /// Upgrade an exposed pung into an added kong.
+    pub fn add_to_kong(&self, tile: Tile) -> Option<Self> {
+        if self.kind == MeldKind::PUNG && self.tiles[0] == tile {
+            let mut tiles = self.tiles.clone();
+            tiles.push(tile);
+            Self::new(MeldKind::KONG(KongKind::ADDED), tiles, self.claim)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_new {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $meld:expr, $expected:literal) => {
+            #[test]
+            fn $name() {
+                assert_eq!($meld.is_some(), $expected);
+            }
+        };
+    }
+
+    const FROM_EAST: u8 = 0;
+
+    create_test!(
+        chow_valid,
+        Meld::chow(
+            [Tile::TONG(3), Tile::TONG(4), Tile::TONG(5)],
+            Claim {
+                tile: Tile::TONG(4),
+                from: FROM_EAST
+            }
+        ),
+        true
+    );
+    create_test!(
+        chow_unordered,
+        Meld::chow(
+            [Tile::WAN(9), Tile::WAN(7), Tile::WAN(8)],
+            Claim {
+                tile: Tile::WAN(9),
+                from: FROM_EAST
+            }
+        ),
+        true
+    );
+    create_test!(
+        chow_gap,
+        Meld::chow(
+            [Tile::TONG(3), Tile::TONG(4), Tile::TONG(6)],
+            Claim {
+                tile: Tile::TONG(4),
+                from: FROM_EAST
+            }
+        ),
+        false
+    );
+    create_test!(
+        chow_mixed_suits,
+        Meld::chow(
+            [Tile::TONG(3), Tile::WAN(4), Tile::TONG(5)],
+            Claim {
+                tile: Tile::TONG(3),
+                from: FROM_EAST
+            }
+        ),
+        false
+    );
+    create_test!(
+        chow_honours,
+        Meld::chow(
+            [Tile::EAST, Tile::SOUTH, Tile::WEST],
+            Claim {
+                tile: Tile::EAST,
+                from: FROM_EAST
+            }
+        ),
+        false
+    );
+    create_test!(
+        chow_claim_not_in_meld,
+        Meld::chow(
+            [Tile::TONG(3), Tile::TONG(4), Tile::TONG(5)],
+            Claim {
+                tile: Tile::TONG(6),
+                from: FROM_EAST
+            }
+        ),
+        false
+    );
+    create_test!(pung_valid, Meld::pung(Tile::CENTRAL, FROM_EAST), true);
+    create_test!(pung_flower, Meld::pung(Tile::PLUM, FROM_EAST), false);
+    create_test!(
+        pung_mixed,
+        Meld::new(
+            MeldKind::PUNG,
+            vec![Tile::EAST, Tile::EAST, Tile::SOUTH],
+            None
+        ),
+        false
+    );
+    create_test!(
+        kong_concealed,
+        Meld::kong(Tile::WAN(1), KongKind::CONCEALED, FROM_EAST),
+        true
+    );
+    create_test!(
+        kong_concealed_with_claim,
+        Meld::new(
+            MeldKind::KONG(KongKind::CONCEALED),
+            vec![Tile::WAN(1); 4],
+            Some(Claim {
+                tile: Tile::WAN(1),
+                from: FROM_EAST
+            })
+        ),
+        false
+    );
+    create_test!(
+        kong_exposed_without_claim,
+        Meld::new(
+            MeldKind::KONG(KongKind::EXPOSED),
+            vec![Tile::WAN(1); 4],
+            None
+        ),
+        false
+    );
+    create_test!(empty, Meld::new(MeldKind::PUNG, vec![], None), false);
+    create_test!(
+        pung_invalid_tile,
+        Meld::pung(Tile::TONG(0), FROM_EAST),
+        false
+    );
+    create_test!(
+        chow_invalid_tile,
+        Meld::chow(
+            [Tile::WAN(8), Tile::WAN(9), Tile::WAN(10)],
+            Claim {
+                tile: Tile::WAN(8),
+                from: FROM_EAST
+            }
+        ),
+        false
+    );
+
+    #[test]
+    fn add_to_kong() {
+        let pung = Meld::pung(Tile::SOUTH, FROM_EAST).unwrap();
+
+        assert_eq!(pung.add_to_kong(Tile::EAST), None);
+        assert_eq!(
+            pung.add_to_kong(Tile::SOUTH),
+            Meld::kong(Tile::SOUTH, KongKind::ADDED, FROM_EAST)
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let meld = Meld::kong(Tile::EAST, KongKind::ADDED, 2).unwrap();
+        let json = serde_json::to_string(&meld).unwrap();
+
+        assert_eq!(serde_json::from_str::<Meld>(&json).unwrap(), meld);
+    }
+
+    macro_rules! create_test {
+        ($name:ident, $json:literal) => {
+            #[test]
+            fn $name() {
+                assert!(serde_json::from_str::<Meld>($json).is_err());
+            }
+        };
+    }
+
+    create_test!(
+        empty_kong,
+        r#"{"kind":{"KONG":"ADDED"},"tiles":[],"claim":{"tile":"EAST","from":1}}"#
+    );
+    create_test!(
+        claim_not_in_meld,
+        r#"{"kind":"PUNG","tiles":["EAST","EAST","EAST"],"claim":{"tile":"SOUTH","from":1}}"#
+    );
+    create_test!(
+        invalid_tile,
+        r#"{"kind":"PUNG","tiles":[{"TONG":0},{"TONG":0},{"TONG":0}],"claim":null}"#
+    );
+}
@@ -7,11 +7,35 @@ pub use category::*;
 
 mod hash;
 
+mod locale;
+#[cfg(feature = "alloc")]
+pub(crate) use locale::localised_tile_name;
+pub use locale::Locale;
+
+#[cfg(feature = "alloc")]
+mod meld;
+#[cfg(feature = "alloc")]
+pub use meld::*;
+
+#[cfg(feature = "alloc")]
+mod name;
+#[cfg(feature = "alloc")]
+pub use name::*;
+
 /// Re-export [`HasSvgData`] trait for use in other modules.
+#[cfg(feature = "std")]
 pub use crate::svg::HasSvgData;
 
+mod physical;
+pub use physical::*;
+
 mod tile;
 pub use tile::*;
 
+mod unicode;
+pub use unicode::*;
+
+#[cfg(feature = "alloc")]
 mod value;
+#[cfg(feature = "alloc")]
 pub(crate) use value::*;
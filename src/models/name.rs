@@ -0,0 +1,225 @@
+//! Parsing of [`Tile`] from Chinese and Japanese names.
+//!
+
+use alloc::string::{String, ToString};
+
+use crate::models::Tile;
+
+/// Errors raised while parsing a tile name with [`Tile::from_name`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TileNameError {
+    /// The name is empty.
+    Empty,
+    /// The name is a numeral without a suit, e.g. `五`.
+    MissingSuit(String),
+    /// The name is a numeral followed by something that is not a suit; holds the
+    /// unrecognised suit.
+    UnknownSuit(String),
+    /// The name is not a tile.
+    Unrecognised(String),
+}
+
+impl core::fmt::Display for TileNameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Tile name is empty."),
+            Self::MissingSuit(name) => write!(f, "{name} is missing a suit, e.g. 万, 筒 or 条."),
+            Self::UnknownSuit(suit) => {
+                write!(f, "{suit} is not a suit; expected e.g. 万, 筒 or 条.")
+            }
+            Self::Unrecognised(name) => write!(f, "{name} is not a tile name."),
+        }
+    }
+}
+
+impl core::error::Error for TileNameError {}
+
+/// Names of the tiles without a value, in simplified and traditional Chinese and
+/// Japanese, including common longer forms.
+const NAMED_TILES: [(&str, Tile); 33] = [
+    ("东", Tile::EAST),
+    ("東", Tile::EAST),
+    ("南", Tile::SOUTH),
+    ("西", Tile::WEST),
+    ("北", Tile::NORTH),
+    ("中", Tile::CENTRAL),
+    ("红中", Tile::CENTRAL),
+    ("紅中", Tile::CENTRAL),
+    ("发", Tile::PROSPERITY),
+    ("發", Tile::PROSPERITY),
+    ("発", Tile::PROSPERITY),
+    ("发财", Tile::PROSPERITY),
+    ("發財", Tile::PROSPERITY),
+    ("白", Tile::BLANK),
+    ("白板", Tile::BLANK),
+    ("梅", Tile::PLUM),
+    ("梅花", Tile::PLUM),
+    ("兰", Tile::ORCHID),
+    ("蘭", Tile::ORCHID),
+    ("兰花", Tile::ORCHID),
+    ("蘭花", Tile::ORCHID),
+    ("菊", Tile::CHRYSANTHEMUM),
+    ("菊花", Tile::CHRYSANTHEMUM),
+    ("竹", Tile::BAMBOO),
+    ("春", Tile::SPRING),
+    ("春天", Tile::SPRING),
+    ("夏", Tile::SUMMER),
+    ("夏天", Tile::SUMMER),
+    ("秋", Tile::AUTUMN),
+    ("秋天", Tile::AUTUMN),
+    ("冬", Tile::WINTER),
+    ("冬天", Tile::WINTER),
+    ("幺鸡", Tile::TIAO(1)),
+];
+
+/// Get the value of a numeral: Arabic, full width, Chinese or financial.
+fn numeral_value(c: char) -> Option<u8> {
+    match c {
+        '1'..='9' => Some(c as u8 - b'0'),
+        '１'..='９' => Some((c as u32 - '０' as u32) as u8),
+        '一' | '壹' | '幺' | '么' => Some(1),
+        '二' | '贰' | '貳' | '两' | '兩' => Some(2),
+        '三' | '叁' | '參' => Some(3),
+        '四' | '肆' => Some(4),
+        '五' | '伍' => Some(5),
+        '六' | '陆' | '陸' => Some(6),
+        '七' | '柒' => Some(7),
+        '八' | '捌' => Some(8),
+        '九' | '玖' => Some(9),
+        _ => None,
+    }
+}
+
+/// Get the tile of a suit with the given value, from any name of the suit.
+fn suited_tile(suit: &str, value: u8) -> Option<Tile> {
+    // `子` is the Japanese suffix for the suits, e.g. `萬子`.
+    let suit = suit.strip_suffix('子').unwrap_or(suit);
+
+    match suit {
+        "万" | "萬" | "マン" | "m" => Some(Tile::WAN(value)),
+        "筒" | "饼" | "餅" | "ピン" | "p" => Some(Tile::TONG(value)),
+        "条" | "條" | "索" | "ソー" | "ソウ" | "s" => Some(Tile::TIAO(value)),
+        _ => None,
+    }
+}
+
+impl Tile {
+    /// Parse a tile from its name in simplified or traditional Chinese, or Japanese.
+    ///
+    /// This is tolerant of the usual variations: suit synonyms such as `索` and `条`,
+    /// `饼` and `筒`, `萬` and `万`; Arabic, full width and financial numerals such
+    /// as `5` and `伍`; winds with `风` or `風`; and longer forms such as `红中`.
+    /// Surrounding whitespace is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mahjong_tiles::{Tile, TileNameError};
+    ///
+    /// assert_eq!(Tile::from_name("五条"), Ok(Tile::TIAO(5)));
+    /// assert_eq!(Tile::from_name("5索"), Ok(Tile::TIAO(5)));
+    /// assert_eq!(Tile::from_name("伍萬"), Ok(Tile::WAN(5)));
+    /// assert_eq!(Tile::from_name("西風"), Ok(Tile::WEST));
+    /// assert_eq!(Tile::from_name("發"), Ok(Tile::PROSPERITY));
+    /// assert_eq!(
+    ///     Tile::from_name("五"),
+    ///     Err(TileNameError::MissingSuit("五".to_string()))
+    /// );
+    /// ```
+    pub fn from_name(name: &str) -> Result<Self, TileNameError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(TileNameError::Empty);
+        }
+
+        let honour = name
+            .strip_suffix(['风', '風'])
+            .filter(|wind| !wind.is_empty())
+            .unwrap_or(name);
+        if let Some((_, tile)) = NAMED_TILES.iter().find(|(known, _)| *known == honour) {
+            return Ok(*tile);
+        }
+
+        let mut chars = name.chars();
+        let Some(value) = chars.next().and_then(numeral_value) else {
+            return Err(TileNameError::Unrecognised(name.to_string()));
+        };
+        let suit = chars.as_str().trim();
+        if suit.is_empty() {
+            return Err(TileNameError::MissingSuit(name.to_string()));
+        }
+
+        suited_tile(suit, value).ok_or(TileNameError::UnknownSuit(suit.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::{IsTile, Locale};
+
+    macro_rules! create_test {
+        ($name:ident, $input:literal, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(Tile::from_name($input), $expected);
+            }
+        };
+    }
+
+    create_test!(simplified_tiao, "五条", Ok(Tile::TIAO(5)));
+    create_test!(japanese_tiao, "5索", Ok(Tile::TIAO(5)));
+    create_test!(traditional_tiao, "九條", Ok(Tile::TIAO(9)));
+    create_test!(bird, "幺鸡", Ok(Tile::TIAO(1)));
+    create_test!(financial_wan, "伍萬", Ok(Tile::WAN(5)));
+    create_test!(full_width_wan, "３万", Ok(Tile::WAN(3)));
+    create_test!(japanese_wan, "7萬子", Ok(Tile::WAN(7)));
+    create_test!(katakana_tong, "1ピン", Ok(Tile::TONG(1)));
+    create_test!(bing_tong, "二饼", Ok(Tile::TONG(2)));
+    create_test!(spaced, " 4 筒 ", Ok(Tile::TONG(4)));
+    create_test!(east, "東", Ok(Tile::EAST));
+    create_test!(west_wind, "西風", Ok(Tile::WEST));
+    create_test!(north_wind, "北风", Ok(Tile::NORTH));
+    create_test!(prosperity, "發", Ok(Tile::PROSPERITY));
+    create_test!(japanese_prosperity, "発", Ok(Tile::PROSPERITY));
+    create_test!(central, "紅中", Ok(Tile::CENTRAL));
+    create_test!(blank, "白板", Ok(Tile::BLANK));
+    create_test!(orchid, "蘭花", Ok(Tile::ORCHID));
+    create_test!(winter, "冬", Ok(Tile::WINTER));
+    create_test!(empty, "  ", Err(TileNameError::Empty));
+    create_test!(
+        missing_suit,
+        "伍",
+        Err(TileNameError::MissingSuit("伍".to_string()))
+    );
+    create_test!(
+        unknown_suit,
+        "五花",
+        Err(TileNameError::UnknownSuit("花".to_string()))
+    );
+    create_test!(
+        zero,
+        "0万",
+        Err(TileNameError::Unrecognised("0万".to_string()))
+    );
+    create_test!(
+        wind_alone,
+        "风",
+        Err(TileNameError::Unrecognised("风".to_string()))
+    );
+
+    #[test]
+    fn localised_names() {
+        [Locale::SIMPLIFIED, Locale::TRADITIONAL, Locale::JAPANESE]
+            .into_iter()
+            .for_each(|locale| {
+                (0..Tile::KINDS).filter_map(Tile::from_id).for_each(|tile| {
+                    assert_eq!(
+                        Tile::from_name(&tile.localised_name(locale).unwrap()),
+                        Ok(tile),
+                        "{locale:?}"
+                    )
+                })
+            });
+    }
+}
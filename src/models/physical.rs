@@ -0,0 +1,405 @@
+//! Physical tiles, telling apart the copies of each [`Tile`] in a set.
+//!
+
+use super::Tile;
+
+/// A single tile in a set, as distinct from the other copies of its kind.
+///
+/// A set holds four copies of every kind of tile, except the flowers and seasons, of
+/// which it holds one each. Each physical tile has an identifier from
+/// [`PhysicalTile::id`] that stays the same however it moves, so that the wall,
+/// replays and UIs can follow the exact tile drawn or discarded.
+///
+/// Whether a tile is red, or a joker, depends on the rules in play, and is carried
+/// alongside its identity rather than being part of it. Tiles are compared, hashed and
+/// ordered by [`PhysicalTile::id`] alone, so a tile equals itself marked red.
+///
+/// With the `serde` feature, tiles are checked as by [`PhysicalTile::new`] and
+/// [`PhysicalTile::with_red`] when deserialised.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::{PhysicalTile, Tile};
+///
+/// let tile = PhysicalTile::new(Tile::TONG(5), 2).unwrap();
+///
+/// assert_eq!(tile.id(), 54);
+/// assert_eq!(PhysicalTile::from_id(54), Some(tile));
+/// assert_eq!(tile.kind(), Tile::TONG(5));
+/// assert_eq!(tile.with_red(), Some(tile));
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedPhysicalTile"))]
+pub struct PhysicalTile {
+    kind: Tile,
+    copy: u8,
+    red: bool,
+    joker: bool,
+}
+
+impl PartialEq for PhysicalTile {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for PhysicalTile {}
+
+impl core::hash::Hash for PhysicalTile {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+impl Ord for PhysicalTile {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.id().cmp(&other.id())
+    }
+}
+
+impl PartialOrd for PhysicalTile {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The fields of a [`PhysicalTile`], as deserialised before they are checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedPhysicalTile {
+    kind: Tile,
+    copy: u8,
+    red: bool,
+    joker: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedPhysicalTile> for PhysicalTile {
+    type Error = &'static str;
+
+    fn try_from(tile: UncheckedPhysicalTile) -> Result<Self, Self::Error> {
+        let mut physical = Self::new(tile.kind, tile.copy).ok_or("no such copy of the tile")?;
+        if tile.red {
+            physical = physical.with_red().ok_or("only fives can be red")?;
+        }
+        if tile.joker {
+            physical = physical.with_joker();
+        }
+        Ok(physical)
+    }
+}
+
+impl Tile {
+    /// Returns the number of copies of this tile in a set: one for the flowers and
+    /// seasons, and four for every other tile.
+    pub const fn copies(&self) -> u8 {
+        if self.is_flower() {
+            1
+        } else {
+            PhysicalTile::COPIES
+        }
+    }
+}
+
+impl PhysicalTile {
+    /// The number of copies of each tile other than the flowers and seasons.
+    pub const COPIES: u8 = 4;
+
+    /// The number of tiles in a set with flowers and seasons; identifiers run from `0`
+    /// to this, exclusive.
+    pub const SET_SIZE: u8 = 144;
+
+    /// The number of tiles in a set without flowers and seasons, as numbered by Tenhou.
+    pub const TENHOU_SET_SIZE: u8 = 136;
+
+    /// Create the given copy of a tile, from `0`.
+    ///
+    /// Returns [`None`] if the tile is not valid, or if the set does not hold that many
+    /// copies of it; see [`Tile::checked_id`] and [`Tile::copies`].
+    pub const fn new(kind: Tile, copy: u8) -> Option<Self> {
+        if kind.checked_id().is_some() && copy < kind.copies() {
+            Some(Self {
+                kind,
+                copy,
+                red: false,
+                joker: false,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the kind of this tile.
+    pub const fn kind(&self) -> Tile {
+        self.kind
+    }
+
+    /// Returns which copy of its kind this tile is, from `0`.
+    pub const fn copy(&self) -> u8 {
+        self.copy
+    }
+
+    /// Returns the identifier of this tile, from `0` to [`PhysicalTile::SET_SIZE`]
+    /// exclusive.
+    ///
+    /// Tiles other than the flowers and seasons are numbered `kind * 4 + copy`, where
+    /// `kind` is [`Tile::id`], which is the `0` to `135` numbering used by Tenhou. The
+    /// flowers and seasons follow, from `136`.
+    pub const fn id(&self) -> u8 {
+        if self.kind.is_flower() {
+            Self::TENHOU_SET_SIZE + self.kind.id() - Tile::PLUM.id()
+        } else {
+            self.kind.id() * Self::COPIES + self.copy
+        }
+    }
+
+    /// Create a tile from its identifier; see [`PhysicalTile::id`].
+    pub const fn from_id(id: u8) -> Option<Self> {
+        if id < Self::TENHOU_SET_SIZE {
+            match Tile::from_id(id / Self::COPIES) {
+                Some(kind) => Self::new(kind, id % Self::COPIES),
+                None => None,
+            }
+        } else if id < Self::SET_SIZE {
+            match Tile::from_id(Tile::PLUM.id() + id - Self::TENHOU_SET_SIZE) {
+                Some(kind) => Self::new(kind, 0),
+                None => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Create a tile from its Tenhou identifier, from `0` to `135`.
+    ///
+    /// With `red_fives`, the first copy of each five is red, as in Tenhou games
+    /// played with red fives.
+    pub const fn from_tenhou_id(id: u8, red_fives: bool) -> Option<Self> {
+        if id >= Self::TENHOU_SET_SIZE {
+            return None;
+        }
+        match Self::from_id(id) {
+            Some(tile) if red_fives && tile.copy == 0 => match tile.with_red() {
+                Some(red) => Some(red),
+                None => Some(tile),
+            },
+            tile => tile,
+        }
+    }
+
+    /// Returns this tile marked as red.
+    ///
+    /// Returns [`None`] if the tile is not a five.
+    pub const fn with_red(self) -> Option<Self> {
+        match self.kind.value() {
+            Some(5) => Some(Self { red: true, ..self }),
+            _ => None,
+        }
+    }
+
+    /// Returns this tile marked as a joker, which can stand in for other tiles.
+    pub const fn with_joker(self) -> Self {
+        Self {
+            joker: true,
+            ..self
+        }
+    }
+
+    /// Returns whether this tile is red.
+    pub const fn is_red(&self) -> bool {
+        self.red
+    }
+
+    /// Returns whether this tile is a joker.
+    pub const fn is_joker(&self) -> bool {
+        self.joker
+    }
+
+    /// Returns whether this is the same physical tile as `other`, whether or not either
+    /// is marked red or as a joker.
+    ///
+    /// This is the same as `==`, but can be used in `const` contexts.
+    pub const fn is_identical_to(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl From<PhysicalTile> for Tile {
+    fn from(tile: PhysicalTile) -> Self {
+        tile.kind
+    }
+}
+
+#[cfg(test)]
+mod test_id {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $kind:expr, $copy:literal, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let tile = PhysicalTile::new($kind, $copy).unwrap();
+
+                assert_eq!(tile.id(), $expected);
+                assert_eq!(PhysicalTile::from_id($expected), Some(tile));
+            }
+        };
+    }
+
+    create_test!(wan1_0, Tile::WAN(1), 0, 0);
+    create_test!(wan9_3, Tile::WAN(9), 3, 35);
+    create_test!(tong1_0, Tile::TONG(1), 0, 36);
+    create_test!(tiao5_0, Tile::TIAO(5), 0, 88);
+    create_test!(east_0, Tile::EAST, 0, 108);
+    create_test!(central_3, Tile::CENTRAL, 3, 135);
+    create_test!(plum_0, Tile::PLUM, 0, 136);
+    create_test!(winter_0, Tile::WINTER, 0, 143);
+
+    #[test]
+    fn every_id() {
+        let tiles = (0..PhysicalTile::SET_SIZE)
+            .map(|id| PhysicalTile::from_id(id).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(tiles
+            .iter()
+            .enumerate()
+            .all(|(id, tile)| usize::from(tile.id()) == id));
+        assert!(tiles.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(PhysicalTile::from_id(PhysicalTile::SET_SIZE), None);
+    }
+
+    #[test]
+    fn copies() {
+        assert_eq!(PhysicalTile::new(Tile::EAST, 4), None);
+        assert_eq!(PhysicalTile::new(Tile::SPRING, 1), None);
+        assert_eq!(PhysicalTile::new(Tile::TONG(10), 0), None);
+        assert_eq!(PhysicalTile::new(Tile::EAST, u8::MAX), None);
+        assert_eq!(Tile::TONG(1).copies(), 4);
+        assert_eq!(Tile::SPRING.copies(), 1);
+    }
+
+    #[test]
+    fn tenhou() {
+        let red = PhysicalTile::from_tenhou_id(52, true).unwrap();
+
+        assert_eq!(red.kind(), Tile::TONG(5));
+        assert!(red.is_red());
+        assert!(!PhysicalTile::from_tenhou_id(52, false).unwrap().is_red());
+        assert!(!PhysicalTile::from_tenhou_id(53, true).unwrap().is_red());
+        assert!(!PhysicalTile::from_tenhou_id(0, true).unwrap().is_red());
+        assert_eq!(PhysicalTile::from_tenhou_id(136, false), None);
+    }
+}
+
+#[cfg(test)]
+mod test_identical_to {
+    use super::*;
+
+    #[test]
+    fn same_tile() {
+        let tile = PhysicalTile::new(Tile::EAST, 0).unwrap();
+
+        assert!(tile.is_identical_to(&tile));
+        assert!(tile.is_identical_to(&tile.with_joker()));
+        assert_eq!(Tile::from(tile), Tile::EAST);
+    }
+
+    #[test]
+    fn different_copy() {
+        let tile1 = PhysicalTile::new(Tile::EAST, 0).unwrap();
+        let tile2 = PhysicalTile::new(Tile::EAST, 1).unwrap();
+
+        assert_eq!(Tile::from(tile1), Tile::from(tile2));
+        assert!(!tile1.is_identical_to(&tile2));
+    }
+
+    #[test]
+    fn red() {
+        let tile = PhysicalTile::new(Tile::WAN(5), 0).unwrap();
+
+        assert!(tile.with_red().unwrap().is_red());
+        assert!(!tile.is_red());
+        assert_eq!(tile.with_red(), Some(tile));
+        assert_eq!(PhysicalTile::new(Tile::WAN(4), 0).unwrap().with_red(), None);
+    }
+}
+
+#[cfg(test)]
+mod test_eq {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ignores_red_and_joker() {
+        let tile = PhysicalTile::new(Tile::TIAO(5), 1).unwrap();
+        let red = tile.with_red().unwrap();
+        let joker = tile.with_joker();
+
+        assert_eq!(tile, red);
+        assert_eq!(tile, joker);
+        assert_eq!(tile.cmp(&red), core::cmp::Ordering::Equal);
+        assert_eq!(HashSet::from([tile, red, joker]).len(), 1);
+    }
+
+    #[test]
+    fn different_copy() {
+        let tile1 = PhysicalTile::new(Tile::TIAO(5), 1).unwrap();
+        let tile2 = PhysicalTile::new(Tile::TIAO(5), 2).unwrap();
+
+        assert_ne!(tile1, tile2);
+        assert!(tile1 < tile2);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let tile = PhysicalTile::new(Tile::WAN(5), 3)
+            .unwrap()
+            .with_red()
+            .unwrap()
+            .with_joker();
+        let decoded: PhysicalTile =
+            serde_json::from_str(&serde_json::to_string(&tile).unwrap()).unwrap();
+
+        assert_eq!(decoded.id(), tile.id());
+        assert!(decoded.is_red());
+        assert!(decoded.is_joker());
+    }
+
+    macro_rules! create_test {
+        ($name:ident, $json:literal) => {
+            #[test]
+            fn $name() {
+                assert!(serde_json::from_str::<PhysicalTile>($json).is_err());
+            }
+        };
+    }
+
+    create_test!(
+        copy_out_of_range,
+        r#"{"kind":{"TONG":5},"copy":7,"red":false,"joker":false}"#
+    );
+    create_test!(
+        copy_overflow,
+        r#"{"kind":"EAST","copy":255,"red":false,"joker":false}"#
+    );
+    create_test!(
+        second_flower,
+        r#"{"kind":"PLUM","copy":3,"red":false,"joker":false}"#
+    );
+    create_test!(
+        red_wind,
+        r#"{"kind":"EAST","copy":0,"red":true,"joker":false}"#
+    );
+    create_test!(
+        invalid_kind,
+        r#"{"kind":{"TONG":10},"copy":0,"red":false,"joker":false}"#
+    );
+}
@@ -1,7 +1,13 @@
-use super::{FlowerCategory, HasChineseValue, HonourCategory, TileCategory};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use super::{localised_tile_name, Locale};
+use super::{FlowerCategory, HonourCategory, TileCategory};
 
 /// A single Mahjong tile.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Tile {
     /// The East wind.
     EAST,
@@ -24,7 +30,7 @@ pub enum Tile {
     /// The Tiao tiles, numbered 1 to 9.
     TIAO(u8),
 
-    // Flower tiles - Not commonly used, no SVG data available.
+    // Flower tiles - Not commonly used.
     /// The Plum flower for Winter.
     PLUM,
     /// The Orchid flower for Spring.
@@ -34,7 +40,7 @@ pub enum Tile {
     /// The Bamboo flower for Summer.
     BAMBOO,
 
-    // Season tiles - Not commonly used, no SVG data available.
+    // Season tiles - Not commonly used.
     /// The Spring season.
     SPRING,
     /// The Summer season.
@@ -45,36 +51,285 @@ pub enum Tile {
     WINTER,
 }
 
-impl PartialOrd for Tile {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.category() == other.category() && !self.is_honour() {
-            if let (Some(v1), Some(v2)) = (self.value(), other.value()) {
-                v1.partial_cmp(&v2)
-            } else {
-                unreachable!("Unreachable: Number tiles should have a value")
-            }
-        } else {
-            None
+/// Tiles deserialise as they serialise, but number tiles valued outside `1` to `9` are
+/// rejected.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Tile {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The derived representation of [`Tile`], with the same variant names.
+        #[allow(clippy::upper_case_acronyms)]
+        #[derive(serde::Deserialize)]
+        #[serde(remote = "Tile", rename = "Tile")]
+        enum RemoteTile {
+            EAST,
+            SOUTH,
+            WEST,
+            NORTH,
+            CENTRAL,
+            PROSPERITY,
+            BLANK,
+            TONG(u8),
+            WAN(u8),
+            TIAO(u8),
+            PLUM,
+            ORCHID,
+            CHRYSANTHEMUM,
+            BAMBOO,
+            SPRING,
+            SUMMER,
+            AUTUMN,
+            WINTER,
+        }
+
+        let tile = RemoteTile::deserialize(deserializer)?;
+        match tile.checked_id() {
+            Some(_) => Ok(tile),
+            None => Err(serde::de::Error::custom(format_args!(
+                "{tile:?} is not a valid tile"
+            ))),
         }
     }
 }
 
+/// Tiles are ordered by [`Tile::suit_order`], then by [`Tile::rank`] within each suit:
+/// Wan, Tong and Tiao from 1 to 9, then the winds, dragons, flowers and seasons. This is
+/// the order of [`Tile::id`], and is consistent with equality, so equal tiles compare
+/// [`Equal`](core::cmp::Ordering::Equal).
+///
+/// To compare tiles only within a suit, compare their ranks when their suit orders are
+/// the same.
+impl Ord for Tile {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.suit_order(), self.rank()).cmp(&(other.suit_order(), other.rank()))
+    }
+}
+
+impl PartialOrd for Tile {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/tile_names.rs"));
+
+/// The simplified Chinese names of the tiles, indexed by [`Tile::id`].
+const CHINESE_NAMES: [&str; Tile::KINDS as usize] = [
+    "一万", "二万", "三万", "四万", "五万", "六万", "七万", "八万", "九万", "一筒", "二筒", "三筒",
+    "四筒", "五筒", "六筒", "七筒", "八筒", "九筒", "一条", "二条", "三条", "四条", "五条", "六条",
+    "七条", "八条", "九条", "东", "南", "西", "北", "白", "发", "中", "梅", "兰", "菊", "竹", "春",
+    "夏", "秋", "冬",
+];
+
 impl Tile {
+    /// The number of distinct kinds of tiles, including flowers and seasons.
+    pub const KINDS: u8 = 42;
+
+    /// Returns the identifier for the kind of this tile, from `0` to [`Tile::KINDS`]
+    /// exclusive.
+    ///
+    /// The number tiles and honours follow the order used by Tenhou: Wan, Tong and
+    /// Tiao from 1 to 9, then the winds from East to North, and the Blank, Prosperity
+    /// and Central dragons, giving `0..34`. Flowers and seasons follow from `34`.
+    ///
+    /// # Panics
+    ///
+    /// If this is a number tile with a value outside `1..=9`; see [`Tile::checked_id`].
+    pub const fn id(&self) -> u8 {
+        match self.checked_id() {
+            Some(id) => id,
+            None => panic!("Unreachable: Value should be between 1 and 9"),
+        }
+    }
+
+    /// Returns the identifier for the kind of this tile, as [`Tile::id`], or [`None`]
+    /// if this is a number tile with a value outside `1..=9`.
+    pub const fn checked_id(&self) -> Option<u8> {
+        match self {
+            Tile::WAN(v @ 1..=9) => Some(*v - 1),
+            Tile::TONG(v @ 1..=9) => Some(9 + *v - 1),
+            Tile::TIAO(v @ 1..=9) => Some(18 + *v - 1),
+            Tile::WAN(_) | Tile::TONG(_) | Tile::TIAO(_) => None,
+            Tile::EAST => Some(27),
+            Tile::SOUTH => Some(28),
+            Tile::WEST => Some(29),
+            Tile::NORTH => Some(30),
+            Tile::BLANK => Some(31),
+            Tile::PROSPERITY => Some(32),
+            Tile::CENTRAL => Some(33),
+            Tile::PLUM => Some(34),
+            Tile::ORCHID => Some(35),
+            Tile::CHRYSANTHEMUM => Some(36),
+            Tile::BAMBOO => Some(37),
+            Tile::SPRING => Some(38),
+            Tile::SUMMER => Some(39),
+            Tile::AUTUMN => Some(40),
+            Tile::WINTER => Some(41),
+        }
+    }
+
+    /// Create a tile from its identifier; see [`Tile::id`].
+    ///
+    /// Like the other constructors and accessors, this is a `const fn`, so tables of
+    /// tiles can be built at compile time.
+    ///
+    /// ```rust
+    /// use mahjong_tiles::Tile;
+    ///
+    /// const TERMINALS: [Option<Tile>; 2] = [Tile::from_id(0), Tile::from_id(8)];
+    ///
+    /// assert_eq!(TERMINALS, [Some(Tile::WAN(1)), Some(Tile::WAN(9))]);
+    /// ```
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0..=8 => Some(Tile::WAN(id + 1)),
+            9..=17 => Some(Tile::TONG(id - 9 + 1)),
+            18..=26 => Some(Tile::TIAO(id - 18 + 1)),
+            27 => Some(Tile::EAST),
+            28 => Some(Tile::SOUTH),
+            29 => Some(Tile::WEST),
+            30 => Some(Tile::NORTH),
+            31 => Some(Tile::BLANK),
+            32 => Some(Tile::PROSPERITY),
+            33 => Some(Tile::CENTRAL),
+            34 => Some(Tile::PLUM),
+            35 => Some(Tile::ORCHID),
+            36 => Some(Tile::CHRYSANTHEMUM),
+            37 => Some(Tile::BAMBOO),
+            38 => Some(Tile::SPRING),
+            39 => Some(Tile::SUMMER),
+            40 => Some(Tile::AUTUMN),
+            41 => Some(Tile::WINTER),
+            _ => None,
+        }
+    }
+
     /// Create a new Mahjong tile with a value.
-    pub fn new_valued(category: TileCategory, value: u8) -> Self {
+    pub const fn new_valued(category: TileCategory, value: u8) -> Self {
         match category {
             TileCategory::TONG => Tile::TONG(value),
             TileCategory::WAN => Tile::WAN(value),
             TileCategory::TIAO => Tile::TIAO(value),
-            _ => unreachable!("Unreachable: Cannot create a non-number tile with a value"),
+            _ => panic!("Unreachable: Cannot create a non-number tile with a value"),
+        }
+    }
+
+    /// Returns the category of the tile; see [`IsTile::category`].
+    pub const fn category(&self) -> TileCategory {
+        match self {
+            Tile::EAST | Tile::SOUTH | Tile::WEST | Tile::NORTH => {
+                TileCategory::HONOUR(HonourCategory::WIND)
+            }
+            Tile::CENTRAL | Tile::PROSPERITY | Tile::BLANK => {
+                TileCategory::HONOUR(HonourCategory::DRAGON)
+            }
+            Tile::TONG(_) => TileCategory::TONG,
+            Tile::WAN(_) => TileCategory::WAN,
+            Tile::TIAO(_) => TileCategory::TIAO,
+            Tile::PLUM | Tile::ORCHID | Tile::CHRYSANTHEMUM | Tile::BAMBOO => {
+                TileCategory::FLOWER(FlowerCategory::FLOWER)
+            }
+            Tile::SPRING | Tile::SUMMER | Tile::AUTUMN | Tile::WINTER => {
+                TileCategory::FLOWER(FlowerCategory::SEASON)
+            }
+        }
+    }
+
+    /// Returns the value of the tile, if it has one; see [`IsTile::value`].
+    pub const fn value(&self) -> Option<u8> {
+        match self {
+            Tile::TONG(v) | Tile::WAN(v) | Tile::TIAO(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the direction of the tile, if it has one; see [`IsTile::direction`].
+    pub const fn direction(&self) -> Option<u8> {
+        match self {
+            Tile::EAST | Tile::SPRING | Tile::PLUM => Some(1),
+            Tile::SOUTH | Tile::SUMMER | Tile::ORCHID => Some(2),
+            Tile::WEST | Tile::AUTUMN | Tile::CHRYSANTHEMUM => Some(3),
+            Tile::NORTH | Tile::WINTER | Tile::BAMBOO => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of the tile's suit in the order of [`Tile::id`], from `0` for
+    /// Wan to `6` for the seasons; the winds and dragons are separate suits here, as are
+    /// the flowers and seasons.
+    pub const fn suit_order(&self) -> u8 {
+        match self.category() {
+            TileCategory::WAN => 0,
+            TileCategory::TONG => 1,
+            TileCategory::TIAO => 2,
+            TileCategory::HONOUR(HonourCategory::WIND) => 3,
+            TileCategory::HONOUR(HonourCategory::DRAGON) => 4,
+            TileCategory::FLOWER(FlowerCategory::FLOWER) => 5,
+            TileCategory::FLOWER(FlowerCategory::SEASON) => 6,
+        }
+    }
+
+    /// Returns the position of the tile within its suit, from `1`.
+    ///
+    /// This is the value of a number tile, the direction of a wind, flower or season, and
+    /// `1` to `3` for the Blank, Prosperity and Central dragons.
+    pub const fn rank(&self) -> u8 {
+        match self {
+            Tile::TONG(v) | Tile::WAN(v) | Tile::TIAO(v) => *v,
+            Tile::BLANK => 1,
+            Tile::PROSPERITY => 2,
+            Tile::CENTRAL => 3,
+            _ => match self.direction() {
+                Some(direction) => direction,
+                None => panic!("Unreachable: Winds, Flowers and Seasons should have a direction"),
+            },
+        }
+    }
+
+    /// Returns whether the tile is an honour tile.
+    pub const fn is_honour(&self) -> bool {
+        matches!(self.category(), TileCategory::HONOUR(_))
+    }
+
+    /// Returns whether the tile is a number tile.
+    pub const fn is_number(&self) -> bool {
+        matches!(self, Tile::TONG(_) | Tile::WAN(_) | Tile::TIAO(_))
+    }
+
+    /// Returns whether the tile is a flower tile.
+    pub const fn is_flower(&self) -> bool {
+        matches!(self.category(), TileCategory::FLOWER(_))
+    }
+
+    /// Get the SVG name for this tile, e.g. `tong-1` or `honour-east`, or [`None`] if
+    /// this is a number tile with a value outside `1..=9`.
+    pub const fn svg_name(&self) -> Option<&'static str> {
+        match self.checked_id() {
+            Some(id) => Some(SVG_NAMES[id as usize]),
+            None => None,
+        }
+    }
+
+    /// Get the name of this tile in simplified Chinese, as [`IsTile::unique_name`]
+    /// but without allocating, or [`None`] if this is a number tile with a value
+    /// outside `1..=9`.
+    pub const fn chinese_name(&self) -> Option<&'static str> {
+        match self.checked_id() {
+            Some(id) => Some(CHINESE_NAMES[id as usize]),
+            None => None,
         }
     }
 }
 
 /// A trait for Mahjong tiles.
-pub trait IsTile: PartialOrd + Eq + std::fmt::Debug + std::hash::Hash {
-    /// Returns the Chinese name of the tile.
-    fn unique_name(&self) -> String;
+pub trait IsTile: Ord + core::fmt::Debug + core::hash::Hash {
+    /// Returns the Chinese name of the tile, or [`None`] if it is not a valid tile.
+    #[cfg(feature = "alloc")]
+    fn unique_name(&self) -> Option<String>;
+
+    /// Returns the name of the tile in the given locale, or [`None`] if it is not a
+    /// valid tile.
+    #[cfg(feature = "alloc")]
+    fn localised_name(&self, locale: Locale) -> Option<String>;
 
     /// Returns the category of the tile.
     fn category(&self) -> TileCategory;
@@ -90,14 +345,6 @@ pub trait IsTile: PartialOrd + Eq + std::fmt::Debug + std::hash::Hash {
         matches!(self.category(), TileCategory::HONOUR(_))
     }
 
-    /// Returns whether the tile is identical to another tile.
-    ///
-    /// This is a reference comparison; different instances of the same tile are not
-    /// considered equal.
-    fn is_identical_to(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
-    }
-
     /// Returns whether the tile is a number tile.
     fn is_number(&self) -> bool {
         matches!(
@@ -111,85 +358,48 @@ pub trait IsTile: PartialOrd + Eq + std::fmt::Debug + std::hash::Hash {
         matches!(self.category(), TileCategory::FLOWER(_))
     }
 
-    /// Get the SVG name for this tile.
-    fn svg_name(&self) -> String {
-        let category_name = self.category().category_name();
-
-        if self.is_honour() {
-            format!("{}-{}", category_name, format!("{:?}", self).to_lowercase())
-        } else {
-            format!(
-                "{}-{}",
-                category_name,
-                self.value()
-                    .expect("Unreachable: Number tiles should have a value")
-            )
-        }
+    /// Get the SVG name for this tile, or [`None`] if it is not a valid tile.
+    fn svg_name(&self) -> Option<&'static str> {
+        self.checked_id()
+            .and_then(Tile::from_id)
+            .and_then(|tile| tile.svg_name())
     }
+
+    /// Returns the identifier of the tile, from `0` to [`Tile::KINDS`] exclusive, as
+    /// [`Tile::checked_id`], or [`None`] if it is not a valid tile.
+    fn checked_id(&self) -> Option<u8>;
 }
 
 impl IsTile for Tile {
     /// Returns the chinese name for the tile.
-    fn unique_name(&self) -> String {
-        match self {
-            Tile::EAST => "东".to_string(),
-            Tile::SOUTH => "南".to_string(),
-            Tile::WEST => "西".to_string(),
-            Tile::NORTH => "北".to_string(),
-            Tile::CENTRAL => "中".to_string(),
-            Tile::PROSPERITY => "发".to_string(),
-            Tile::BLANK => "白".to_string(),
-            Tile::TIAO(v) | Tile::TONG(v) | Tile::WAN(v) => {
-                let category_name = self.category().category_name_chinese();
-                let chinese_value = v.chinese_value();
-                format!("{}{}", chinese_value, category_name)
-            }
-            Tile::PLUM => "梅".to_string(),
-            Tile::ORCHID => "兰".to_string(),
-            Tile::CHRYSANTHEMUM => "菊".to_string(),
-            Tile::BAMBOO => "竹".to_string(),
-            Tile::SPRING => "春".to_string(),
-            Tile::SUMMER => "夏".to_string(),
-            Tile::AUTUMN => "秋".to_string(),
-            Tile::WINTER => "冬".to_string(),
-        }
+    #[cfg(feature = "alloc")]
+    fn unique_name(&self) -> Option<String> {
+        self.chinese_name().map(String::from)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn localised_name(&self, locale: Locale) -> Option<String> {
+        localised_tile_name(self, locale)
     }
 
     fn category(&self) -> TileCategory {
-        match self {
-            Tile::EAST | Tile::SOUTH | Tile::WEST | Tile::NORTH => {
-                TileCategory::HONOUR(HonourCategory::WIND)
-            }
-            Tile::CENTRAL | Tile::PROSPERITY | Tile::BLANK => {
-                TileCategory::HONOUR(HonourCategory::DRAGON)
-            }
-            Tile::TONG(_) => TileCategory::TONG,
-            Tile::WAN(_) => TileCategory::WAN,
-            Tile::TIAO(_) => TileCategory::TIAO,
-            Tile::PLUM | Tile::ORCHID | Tile::CHRYSANTHEMUM | Tile::BAMBOO => {
-                TileCategory::FLOWER(FlowerCategory::FLOWER)
-            }
-            Tile::SPRING | Tile::SUMMER | Tile::AUTUMN | Tile::WINTER => {
-                TileCategory::FLOWER(FlowerCategory::SEASON)
-            }
-        }
+        Tile::category(self)
     }
 
     fn value(&self) -> Option<u8> {
-        match self {
-            Tile::TONG(v) | Tile::WAN(v) | Tile::TIAO(v) => Some(*v),
-            _ => None,
-        }
+        Tile::value(self)
     }
 
     fn direction(&self) -> Option<u8> {
-        match self {
-            Tile::EAST | Tile::SPRING | Tile::PLUM => Some(1),
-            Tile::SOUTH | Tile::SUMMER | Tile::ORCHID => Some(2),
-            Tile::WEST | Tile::AUTUMN | Tile::CHRYSANTHEMUM => Some(3),
-            Tile::NORTH | Tile::WINTER | Tile::BAMBOO => Some(4),
-            _ => None,
-        }
+        Tile::direction(self)
+    }
+
+    fn svg_name(&self) -> Option<&'static str> {
+        Tile::svg_name(self)
+    }
+
+    fn checked_id(&self) -> Option<u8> {
+        Tile::checked_id(self)
     }
 }
 
@@ -225,84 +435,111 @@ mod test_eq {
 }
 
 #[cfg(test)]
-#[allow(clippy::neg_cmp_op_on_partial_ord)]
 mod test_ord {
     use super::*;
     use std::cmp::Ordering;
+
     macro_rules! create_test {
-        ($name:ident, $st:expr, $expected:literal) => {
-            #[test]
-            fn $name() {
-                if $expected {
-                    assert!($st);
-                } else {
-                    assert!(!($st));
-                }
-            }
-        };
         ($name:ident, $lhs:expr, $rhs:expr, $expected:expr) => {
             #[test]
             fn $name() {
-                assert_eq!($lhs.partial_cmp(&$rhs), $expected);
+                assert_eq!($lhs.cmp(&$rhs), $expected);
+                assert_eq!($lhs.partial_cmp(&$rhs), Some($expected));
+                assert_eq!($rhs.cmp(&$lhs), $expected.reverse());
             }
         };
     }
 
-    create_test!(east_cmp_east, Tile::EAST, Tile::EAST, None);
-    create_test!(east_cmp_south, Tile::EAST, Tile::SOUTH, None);
-    create_test!(east_lt_east, Tile::EAST < Tile::EAST, false);
-
-    create_test!(east_le_east, Tile::EAST <= Tile::EAST, false);
-    create_test!(east_gt_east, Tile::EAST > Tile::EAST, false);
-    create_test!(east_ge_east, Tile::EAST >= Tile::EAST, false);
-    create_test!(east_lt_south, Tile::EAST < Tile::SOUTH, false);
-    create_test!(east_lt_central, Tile::EAST < Tile::CENTRAL, false);
-    create_test!(east_lt_tong1, Tile::EAST < Tile::TONG(1), false);
-    create_test!(east_lt_wan1, Tile::EAST < Tile::WAN(1), false);
-    create_test!(east_lt_tiao1, Tile::EAST < Tile::TIAO(1), false);
-    create_test!(central_cmp_central, Tile::CENTRAL, Tile::CENTRAL, None);
-    create_test!(central_lt_central, Tile::CENTRAL < Tile::CENTRAL, false);
+    create_test!(east_cmp_east, Tile::EAST, Tile::EAST, Ordering::Equal);
+    create_test!(east_cmp_south, Tile::EAST, Tile::SOUTH, Ordering::Less);
+    create_test!(north_cmp_blank, Tile::NORTH, Tile::BLANK, Ordering::Less);
+    create_test!(
+        central_cmp_central,
+        Tile::CENTRAL,
+        Tile::CENTRAL,
+        Ordering::Equal
+    );
     create_test!(
         central_cmp_prosperity,
         Tile::CENTRAL,
         Tile::PROSPERITY,
-        None
+        Ordering::Greater
     );
+    create_test!(central_cmp_plum, Tile::CENTRAL, Tile::PLUM, Ordering::Less);
     create_test!(
-        central_lt_prosperity,
-        Tile::CENTRAL < Tile::PROSPERITY,
-        false
+        bamboo_cmp_spring,
+        Tile::BAMBOO,
+        Tile::SPRING,
+        Ordering::Less
     );
     create_test!(
         tong1_cmp_tong1,
         Tile::TONG(1),
         Tile::TONG(1),
-        Some(Ordering::Equal)
+        Ordering::Equal
     );
-    create_test!(tong1_lt_tong1, Tile::TONG(1) < Tile::TONG(1), false);
-    create_test!(tong1_le_tong1, Tile::TONG(1) <= Tile::TONG(1), true);
     create_test!(
         tong1_cmp_tong2,
         Tile::TONG(1),
         Tile::TONG(2),
-        Some(Ordering::Less)
+        Ordering::Less
     );
-    create_test!(tong1_lt_tong2, Tile::TONG(1) < Tile::TONG(2), true);
-    create_test!(tong1_cmp_wan1, Tile::TONG(1), Tile::WAN(1), None);
-    create_test!(tong1_lt_wan1, Tile::TONG(1) < Tile::WAN(1), false);
-    create_test!(tong1_cmp_central, Tile::TONG(1), Tile::CENTRAL, None);
-    create_test!(tong1_lt_central, Tile::TONG(1) < Tile::CENTRAL, false);
-    create_test!(tong1_cmp_tiao1, Tile::TONG(1), Tile::TIAO(1), None);
-    create_test!(tong1_lt_tiao1, Tile::TONG(1) < Tile::TIAO(1), false);
     create_test!(
         tong9_cmp_tong1,
         Tile::TONG(9),
         Tile::TONG(1),
-        Some(Ordering::Greater)
+        Ordering::Greater
+    );
+    create_test!(wan9_cmp_tong1, Tile::WAN(9), Tile::TONG(1), Ordering::Less);
+    create_test!(
+        tong9_cmp_tiao1,
+        Tile::TONG(9),
+        Tile::TIAO(1),
+        Ordering::Less
     );
-    create_test!(tong9_lt_tong1, Tile::TONG(9) < Tile::TONG(1), false);
-    create_test!(tong9_ge_tong1, Tile::TONG(9) >= Tile::TONG(1), true);
-    create_test!(tong9_ge_wan1, Tile::TONG(9) >= Tile::WAN(1), false);
+    create_test!(tiao9_cmp_east, Tile::TIAO(9), Tile::EAST, Ordering::Less);
+
+    #[test]
+    fn operators() {
+        assert!(Tile::EAST <= Tile::EAST);
+        assert!(Tile::EAST >= Tile::EAST);
+        assert_eq!(Tile::EAST.max(Tile::SOUTH), Tile::SOUTH);
+        assert!(Tile::TONG(1) < Tile::TONG(2));
+        assert!(Tile::TONG(9) >= Tile::WAN(1));
+    }
+
+    #[test]
+    fn consistent_with_eq() {
+        let tiles = (0..Tile::KINDS).filter_map(Tile::from_id);
+        for lhs in tiles.clone() {
+            for rhs in tiles.clone() {
+                assert_eq!(lhs == rhs, lhs.cmp(&rhs) == Ordering::Equal);
+            }
+        }
+    }
+
+    #[test]
+    fn follows_id() {
+        let tiles = (0..Tile::KINDS)
+            .filter_map(Tile::from_id)
+            .collect::<Vec<_>>();
+
+        assert!(tiles.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn within_suit() {
+        let same_suit = |lhs: Tile, rhs: Tile| lhs.suit_order() == rhs.suit_order();
+
+        assert!(same_suit(Tile::TONG(1), Tile::TONG(9)));
+        assert!(Tile::TONG(1).rank() < Tile::TONG(9).rank());
+        assert!(!same_suit(Tile::TONG(1), Tile::WAN(1)));
+        assert!(!same_suit(Tile::EAST, Tile::CENTRAL));
+        assert!(!same_suit(Tile::PLUM, Tile::SPRING));
+        assert_eq!(Tile::PLUM.rank(), Tile::SPRING.rank());
+        assert_eq!(Tile::BLANK.rank(), 1);
+        assert_eq!(Tile::NORTH.rank(), 4);
+    }
 }
 
 #[cfg(test)]
@@ -318,14 +555,19 @@ mod test_metadata {
         };
     }
 
-    create_test!(blank_svg_name, Tile::BLANK, svg_name, "honour-blank");
+    create_test!(blank_svg_name, Tile::BLANK, svg_name, Some("honour-blank"));
     create_test!(
         central_category,
         Tile::CENTRAL,
         category,
         TileCategory::HONOUR(HonourCategory::DRAGON)
     );
-    create_test!(central_svg_name, Tile::CENTRAL, svg_name, "honour-central");
+    create_test!(
+        central_svg_name,
+        Tile::CENTRAL,
+        svg_name,
+        Some("honour-central")
+    );
     create_test!(central_value, Tile::CENTRAL, value, None);
     create_test!(
         east_category,
@@ -333,67 +575,151 @@ mod test_metadata {
         category,
         TileCategory::HONOUR(HonourCategory::WIND)
     );
-    create_test!(east_svg_name, Tile::EAST, svg_name, "honour-east");
+    create_test!(east_svg_name, Tile::EAST, svg_name, Some("honour-east"));
     create_test!(east_value, Tile::EAST, value, None);
+    create_test!(
+        chrysanthemum_svg_name,
+        Tile::CHRYSANTHEMUM,
+        svg_name,
+        Some("flower-chrysanthemum")
+    );
+    create_test!(plum_svg_name, Tile::PLUM, svg_name, Some("flower-plum"));
+    create_test!(
+        spring_svg_name,
+        Tile::SPRING,
+        svg_name,
+        Some("season-spring")
+    );
+    create_test!(
+        winter_svg_name,
+        Tile::WINTER,
+        svg_name,
+        Some("season-winter")
+    );
     create_test!(
         prosperity_svg_name,
         Tile::PROSPERITY,
         svg_name,
-        "honour-prosperity"
+        Some("honour-prosperity")
     );
     create_test!(tiao5_category, Tile::TIAO(5), category, TileCategory::TIAO);
-    create_test!(tiao5_svg_name, Tile::TIAO(5), svg_name, "tiao-5");
+    create_test!(tiao5_svg_name, Tile::TIAO(5), svg_name, Some("tiao-5"));
     create_test!(tiao5_value, Tile::TIAO(5), value, Some(5));
     create_test!(tong1_category, Tile::TONG(1), category, TileCategory::TONG);
-    create_test!(tong1_svg_name, Tile::TONG(1), svg_name, "tong-1");
+    create_test!(tong1_svg_name, Tile::TONG(1), svg_name, Some("tong-1"));
     create_test!(tong1_value, Tile::TONG(1), value, Some(1));
     create_test!(wan9_category, Tile::WAN(9), category, TileCategory::WAN);
-    create_test!(wan9_svg_name, Tile::WAN(9), svg_name, "wan-9");
+    create_test!(wan9_svg_name, Tile::WAN(9), svg_name, Some("wan-9"));
     create_test!(wan9_value, Tile::WAN(9), value, Some(9));
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod test_chinese_names {
     use super::*;
     macro_rules! create_test {
-        ($name:ident, $tile:expr, $expected:literal) => {
+        ($name:ident, $tile:expr, $expected:expr) => {
             #[test]
             fn $name() {
-                assert_eq!($tile.unique_name(), $expected);
+                assert_eq!($tile.unique_name().as_deref(), $expected);
             }
         };
     }
 
-    create_test!(blank_chinese_name, Tile::BLANK, "白");
-    create_test!(central_chinese_name, Tile::CENTRAL, "中");
-    create_test!(east_chinese_name, Tile::EAST, "东");
-    create_test!(prosperity_chinese_name, Tile::PROSPERITY, "发");
-    create_test!(tiao5_chinese_name, Tile::TIAO(5), "五条");
-    create_test!(tong1_chinese_name, Tile::TONG(1), "一筒");
-    create_test!(wan9_chinese_name, Tile::WAN(9), "九万");
-    create_test!(orchid_chinese_name, Tile::ORCHID, "兰");
-    create_test!(plum_chinese_name, Tile::PLUM, "梅");
-    create_test!(chrysanthemum_chinese_name, Tile::CHRYSANTHEMUM, "菊");
-    create_test!(bamboo_chinese_name, Tile::BAMBOO, "竹");
+    create_test!(blank_chinese_name, Tile::BLANK, Some("白"));
+    create_test!(central_chinese_name, Tile::CENTRAL, Some("中"));
+    create_test!(east_chinese_name, Tile::EAST, Some("东"));
+    create_test!(prosperity_chinese_name, Tile::PROSPERITY, Some("发"));
+    create_test!(tiao5_chinese_name, Tile::TIAO(5), Some("五条"));
+    create_test!(tong1_chinese_name, Tile::TONG(1), Some("一筒"));
+    create_test!(wan9_chinese_name, Tile::WAN(9), Some("九万"));
+    create_test!(orchid_chinese_name, Tile::ORCHID, Some("兰"));
+    create_test!(plum_chinese_name, Tile::PLUM, Some("梅"));
+    create_test!(chrysanthemum_chinese_name, Tile::CHRYSANTHEMUM, Some("菊"));
+    create_test!(bamboo_chinese_name, Tile::BAMBOO, Some("竹"));
+    create_test!(tong10_chinese_name, Tile::TONG(10), None);
+    create_test!(wan0_chinese_name, Tile::WAN(0), None);
 }
 
 #[cfg(test)]
-mod test_identical_to {
+mod test_id {
     use super::*;
 
-    fn make_tiles() -> (Tile, Tile) {
-        (Tile::EAST, Tile::EAST)
+    macro_rules! create_test {
+        ($name:ident, $tile:expr, $expected:literal) => {
+            #[test]
+            fn $name() {
+                assert_eq!($tile.id(), $expected);
+                assert_eq!(Tile::from_id($expected), Some($tile));
+            }
+        };
     }
 
+    create_test!(wan1_id, Tile::WAN(1), 0);
+    create_test!(wan9_id, Tile::WAN(9), 8);
+    create_test!(tong1_id, Tile::TONG(1), 9);
+    create_test!(tiao9_id, Tile::TIAO(9), 26);
+    create_test!(east_id, Tile::EAST, 27);
+    create_test!(blank_id, Tile::BLANK, 31);
+    create_test!(central_id, Tile::CENTRAL, 33);
+    create_test!(plum_id, Tile::PLUM, 34);
+    create_test!(winter_id, Tile::WINTER, 41);
+
     #[test]
-    fn same_tile() {
-        let (tile1, _) = make_tiles();
-        assert!(tile1.is_identical_to(&tile1));
+    fn const_table() {
+        const WINDS: [Option<Tile>; 4] = [
+            Tile::from_id(27),
+            Tile::from_id(28),
+            Tile::from_id(29),
+            Tile::from_id(30),
+        ];
+        const NAME: Option<&str> = Tile::EAST.svg_name();
+        const NEXT: Option<Tile> = Tile::WAN(1).checked_add(1);
+
+        assert_eq!(
+            WINDS,
+            [Tile::EAST, Tile::SOUTH, Tile::WEST, Tile::NORTH].map(Some)
+        );
+        assert_eq!(NAME, Some("honour-east"));
+        assert_eq!(NEXT, Some(Tile::WAN(2)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(Tile::from_id(Tile::KINDS), None);
+        assert_eq!(Tile::from_id(u8::MAX), None);
+    }
+
+    #[test]
+    fn invalid_value() {
+        for tile in [Tile::TONG(10), Tile::WAN(0), Tile::TIAO(u8::MAX)] {
+            assert_eq!(tile.checked_id(), None);
+            assert_eq!(tile.svg_name(), None);
+            assert_eq!(tile.chinese_name(), None);
+        }
+        assert_eq!(Tile::TONG(5).checked_id(), Some(Tile::TONG(5).id()));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for tile in [Tile::TONG(5), Tile::EAST, Tile::WINTER] {
+            let json = serde_json::to_string(&tile).unwrap();
+            assert_eq!(serde_json::from_str::<Tile>(&json).unwrap(), tile);
+        }
+        assert_eq!(
+            serde_json::to_string(&Tile::WAN(1)).unwrap(),
+            r#"{"WAN":1}"#
+        );
     }
 
     #[test]
-    fn different_tile() {
-        let (tile1, tile2) = make_tiles();
-        assert!(!tile1.is_identical_to(&tile2));
+    fn invalid_value() {
+        assert!(serde_json::from_str::<Tile>(r#"{"TONG":0}"#).is_err());
+        assert!(serde_json::from_str::<Tile>(r#"{"WAN":10}"#).is_err());
+        assert!(serde_json::from_str::<Tile>(r#""RED""#).is_err());
     }
 }
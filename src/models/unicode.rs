@@ -0,0 +1,131 @@
+//! Conversion between [`Tile`] and the Unicode Mahjong Tiles block, `U+1F000..=U+1F02B`.
+//!
+
+use crate::models::Tile;
+
+/// The first character of the Mahjong Tiles block, the East wind.
+const BLOCK_START: u32 = 0x1F000;
+
+/// The character for the back of a tile, which is not any [`Tile`].
+pub const UNICODE_TILE_BACK: char = '\u{1F02B}';
+
+impl Tile {
+    /// Returns the character for this tile in the Unicode Mahjong Tiles block, or
+    /// [`None`] if this is a number tile with a value outside `1..=9`.
+    ///
+    /// Unicode names the suits after their riichi forms: Wan are Characters, Tong are
+    /// Circles and Tiao are Bamboos.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mahjong_tiles::Tile;
+    ///
+    /// assert_eq!(Tile::TONG(5).to_unicode(), Some('🀝'));
+    /// assert_eq!(Tile::TONG(10).to_unicode(), None);
+    /// assert_eq!(Tile::from_unicode('🀄'), Some(Tile::CENTRAL));
+    /// ```
+    pub const fn to_unicode(&self) -> Option<char> {
+        let offset = match self {
+            Tile::EAST => 0x00,
+            Tile::SOUTH => 0x01,
+            Tile::WEST => 0x02,
+            Tile::NORTH => 0x03,
+            Tile::CENTRAL => 0x04,
+            Tile::PROSPERITY => 0x05,
+            Tile::BLANK => 0x06,
+            Tile::WAN(v @ 1..=9) => 0x07 + *v as u32 - 1,
+            Tile::TIAO(v @ 1..=9) => 0x10 + *v as u32 - 1,
+            Tile::TONG(v @ 1..=9) => 0x19 + *v as u32 - 1,
+            Tile::PLUM => 0x22,
+            Tile::ORCHID => 0x23,
+            Tile::BAMBOO => 0x24,
+            Tile::CHRYSANTHEMUM => 0x25,
+            Tile::SPRING => 0x26,
+            Tile::SUMMER => 0x27,
+            Tile::AUTUMN => 0x28,
+            Tile::WINTER => 0x29,
+            Tile::WAN(_) | Tile::TIAO(_) | Tile::TONG(_) => return None,
+        };
+
+        char::from_u32(BLOCK_START + offset)
+    }
+
+    /// Create a tile from its character in the Unicode Mahjong Tiles block.
+    ///
+    /// Returns [`None`] for any other character, including the joker and
+    /// [`UNICODE_TILE_BACK`].
+    pub const fn from_unicode(c: char) -> Option<Self> {
+        let Some(offset) = (c as u32).checked_sub(BLOCK_START) else {
+            return None;
+        };
+        match offset {
+            0x00 => Some(Tile::EAST),
+            0x01 => Some(Tile::SOUTH),
+            0x02 => Some(Tile::WEST),
+            0x03 => Some(Tile::NORTH),
+            0x04 => Some(Tile::CENTRAL),
+            0x05 => Some(Tile::PROSPERITY),
+            0x06 => Some(Tile::BLANK),
+            0x07..=0x0F => Some(Tile::WAN((offset - 0x07 + 1) as u8)),
+            0x10..=0x18 => Some(Tile::TIAO((offset - 0x10 + 1) as u8)),
+            0x19..=0x21 => Some(Tile::TONG((offset - 0x19 + 1) as u8)),
+            0x22 => Some(Tile::PLUM),
+            0x23 => Some(Tile::ORCHID),
+            0x24 => Some(Tile::BAMBOO),
+            0x25 => Some(Tile::CHRYSANTHEMUM),
+            0x26 => Some(Tile::SPRING),
+            0x27 => Some(Tile::SUMMER),
+            0x28 => Some(Tile::AUTUMN),
+            0x29 => Some(Tile::WINTER),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $tile:expr, $expected:literal) => {
+            #[test]
+            fn $name() {
+                assert_eq!($tile.to_unicode(), Some($expected));
+                assert_eq!(Tile::from_unicode($expected), Some($tile));
+            }
+        };
+    }
+
+    create_test!(east, Tile::EAST, '🀀');
+    create_test!(central, Tile::CENTRAL, '🀄');
+    create_test!(blank, Tile::BLANK, '🀆');
+    create_test!(wan1, Tile::WAN(1), '🀇');
+    create_test!(tiao9, Tile::TIAO(9), '🀘');
+    create_test!(tong1, Tile::TONG(1), '🀙');
+    create_test!(bamboo, Tile::BAMBOO, '🀤');
+    create_test!(chrysanthemum, Tile::CHRYSANTHEMUM, '🀥');
+    create_test!(winter, Tile::WINTER, '🀩');
+
+    #[test]
+    fn round_trip() {
+        (0..Tile::KINDS).filter_map(Tile::from_id).for_each(|tile| {
+            assert_eq!(tile.to_unicode().and_then(Tile::from_unicode), Some(tile))
+        });
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(Tile::TONG(0).to_unicode(), None);
+        assert_eq!(Tile::TONG(10).to_unicode(), None);
+        assert_eq!(Tile::WAN(u8::MAX).to_unicode(), None);
+    }
+
+    #[test]
+    fn not_a_tile() {
+        assert_eq!(Tile::from_unicode('\u{1F02A}'), None);
+        assert_eq!(Tile::from_unicode(UNICODE_TILE_BACK), None);
+        assert_eq!(Tile::from_unicode('\u{1F02C}'), None);
+        assert_eq!(Tile::from_unicode('A'), None);
+    }
+}
@@ -0,0 +1,408 @@
+//! Python bindings through [PyO3](https://pyo3.rs), built into a module with
+//! [maturin](https://www.maturin.rs) by `maturin develop`.
+//!
+//! ```python
+//! from mahjong_tiles import Tile, parse_hand, shanten, waits
+//!
+//! hand = parse_hand("23m456p789s11155z")
+//! shanten(hand)  # 0
+//! waits(hand)  # [Tile('一万'), Tile('四万')]
+//! sorted({Tile("東"), Tile("5p")})  # [Tile('五筒'), Tile('东')]
+//! ```
+//!
+//! Tiles are ordered by [`Tile::id`], the same order as hands are formatted in, so
+//! that lists of tiles can be sorted.
+//!
+//! Concealed hands are scored with `score`, as by [`hand::score`]:
+//!
+//! ```python
+//! from mahjong_tiles import Tile, parse_hand, score
+//!
+//! result = score(parse_hand("234m456p678s88p234s"), "riichi", seat_wind=Tile("南"))
+//! result.patterns  # [('Pinfu', 1), ('All simples', 1)]
+//! result.points  # 2000
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::hand;
+use crate::models::{IsTile, Tile, TileCategory};
+use crate::svg::{HasSvgData, SvgStyle};
+
+/// Hash a value the same way as its Rust [`Hash`] implementation.
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Convert a Rust error into a Python `ValueError`.
+fn value_error(error: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Convert Python tiles into tiles.
+fn tiles(hand: Vec<PyTile>) -> Vec<Tile> {
+    hand.into_iter().map(|tile| tile.0).collect()
+}
+
+/// Convert tiles into Python tiles.
+fn py_tiles(tiles: Vec<Tile>) -> Vec<PyTile> {
+    tiles.into_iter().map(PyTile).collect()
+}
+
+/// The category of a tile, such as `tong` or `honour`.
+#[pyclass(
+    name = "TileCategory",
+    module = "mahjong_tiles",
+    frozen,
+    from_py_object
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PyTileCategory(TileCategory);
+
+#[pymethods]
+impl PyTileCategory {
+    /// The name of the category, e.g. `"tong"`.
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.0.category_name()
+    }
+
+    /// The name of the category in Chinese, e.g. `"筒"`.
+    #[getter]
+    fn chinese_name(&self) -> &'static str {
+        self.0.category_name_chinese()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<TileCategory {:?}>", self.0)
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.0.category_name()
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.0)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// A single tile, created from its name in Chinese, Japanese or English, or in mpsz
+/// notation, e.g. `Tile("五筒")`, `Tile("ピン5")` or `Tile("5p")`.
+#[pyclass(name = "Tile", module = "mahjong_tiles", frozen, from_py_object)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PyTile(Tile);
+
+#[pymethods]
+impl PyTile {
+    #[new]
+    fn new(name: &str) -> PyResult<Self> {
+        Tile::from_name(name).map(Self).map_err(value_error)
+    }
+
+    /// Create a tile from its identifier, from 0 to 41.
+    #[staticmethod]
+    fn from_id(id: u8) -> PyResult<Self> {
+        Tile::from_id(id)
+            .map(Self)
+            .ok_or_else(|| value_error(format!("{id} is not a tile id.")))
+    }
+
+    /// Create a tile from its character in the Unicode Mahjong Tiles block.
+    #[staticmethod]
+    fn from_unicode(character: char) -> PyResult<Self> {
+        Tile::from_unicode(character)
+            .map(Self)
+            .ok_or_else(|| value_error(format!("{character:?} is not a Mahjong tile.")))
+    }
+
+    /// The identifier of this tile, from 0 to 41.
+    #[getter]
+    fn id(&self) -> u8 {
+        self.0.id()
+    }
+
+    /// The category of this tile.
+    #[getter]
+    fn category(&self) -> PyTileCategory {
+        PyTileCategory(self.0.category())
+    }
+
+    /// The number on this tile, or `None` for honours and flowers.
+    #[getter]
+    fn value(&self) -> Option<u8> {
+        self.0.value()
+    }
+
+    /// The Chinese name of this tile.
+    #[getter]
+    fn name(&self) -> String {
+        self.0
+            .unique_name()
+            .expect("Tiles from names should have Chinese names.")
+    }
+
+    /// The name of the artwork for this tile, e.g. `"tong-5"`.
+    #[getter]
+    fn svg_name(&self) -> &'static str {
+        self.0
+            .svg_name()
+            .expect("Tiles from names should have SVG names.")
+    }
+
+    /// The character for this tile in the Unicode Mahjong Tiles block.
+    #[getter]
+    fn unicode(&self) -> char {
+        self.0
+            .to_unicode()
+            .expect("Tiles from names should have Unicode characters.")
+    }
+
+    /// Whether this is a wind or a dragon.
+    #[getter]
+    fn is_honour(&self) -> bool {
+        self.0.is_honour()
+    }
+
+    /// Whether this is a flower or a season.
+    #[getter]
+    fn is_flower(&self) -> bool {
+        self.0.is_flower()
+    }
+
+    /// The artwork for this tile as a base64 data URI, in a style such as `"light"`.
+    fn svg_data(&self, style: &str) -> PyResult<Option<&'static str>> {
+        let style = SvgStyle::from_name(style)
+            .ok_or_else(|| value_error(format!("{style:?} is not an embedded style.")))?;
+        Ok(self.0.svg_data(style))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Tile('{}')", self.name())
+    }
+
+    fn __str__(&self) -> String {
+        self.name()
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.0)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.0.id().cmp(&other.0.id()))
+    }
+}
+
+/// Parse a hand in mpsz notation, e.g. `"123m456p789s1122z"`.
+#[pyfunction]
+fn parse_hand(notation: &str) -> PyResult<Vec<PyTile>> {
+    hand::parse_hand(notation)
+        .map(py_tiles)
+        .map_err(value_error)
+}
+
+/// Format a hand in mpsz notation, sorted by suit and number.
+#[pyfunction]
+fn format_hand(hand: Vec<PyTile>) -> PyResult<String> {
+    hand::format_hand(&tiles(hand)).map_err(value_error)
+}
+
+/// The shanten of a hand: `0` if it is ready, and `-1` if it is complete.
+#[pyfunction]
+fn shanten(hand: Vec<PyTile>) -> PyResult<i8> {
+    hand::shanten(&tiles(hand)).map_err(value_error)
+}
+
+/// The tiles that would complete a ready hand.
+#[pyfunction]
+fn waits(hand: Vec<PyTile>) -> PyResult<Vec<PyTile>> {
+    hand::waits(&tiles(hand)).map(py_tiles).map_err(value_error)
+}
+
+/// The score of a winning hand.
+#[pyclass(name = "Score", module = "mahjong_tiles", frozen, skip_from_py_object)]
+#[derive(Debug, Clone)]
+pub struct PyScore(hand::Score);
+
+#[pymethods]
+impl PyScore {
+    /// The patterns the hand scores, as pairs of their names and values.
+    #[getter]
+    fn patterns(&self) -> Vec<(&'static str, u32)> {
+        self.0
+            .patterns
+            .iter()
+            .map(|pattern| (pattern.name, pattern.value))
+            .collect()
+    }
+
+    /// The total value of the hand, in faan, han or fan.
+    #[getter]
+    fn value(&self) -> u32 {
+        self.0.value
+    }
+
+    /// The fu of the hand, under riichi rules for hands other than yakuman.
+    #[getter]
+    fn fu(&self) -> Option<u32> {
+        self.0.fu
+    }
+
+    /// The points won, under riichi rules.
+    #[getter]
+    fn points(&self) -> Option<u32> {
+        self.0.points
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Score {} {:?}>", self.0.value, self.patterns())
+    }
+}
+
+/// Score a concealed winning hand by `"hk"`, `"riichi"` or `"mcr"` rules.
+///
+/// The hand includes the winning tile, which is the last tile other than a flower
+/// unless `win` is given. Both winds default to East. Hands with declared melds can
+/// only be scored from Rust, as melds are not exposed to Python.
+#[pyfunction]
+#[pyo3(signature = (hand, rules, win=None, self_drawn=false, seat_wind=None, round_wind=None))]
+fn score(
+    hand: Vec<PyTile>,
+    rules: &str,
+    win: Option<PyTile>,
+    self_drawn: bool,
+    seat_wind: Option<PyTile>,
+    round_wind: Option<PyTile>,
+) -> PyResult<PyScore> {
+    let rules = match rules {
+        "hk" => hand::Rules::HK,
+        "riichi" => hand::Rules::RIICHI,
+        "mcr" => hand::Rules::MCR,
+        _ => return Err(value_error(format!("{rules:?} is not hk, riichi or mcr."))),
+    };
+    let tiles = tiles(hand);
+    let win = hand::Win {
+        tile: match win {
+            Some(win) => win.0,
+            None => *tiles
+                .iter()
+                .rfind(|tile| !tile.is_flower())
+                .ok_or_else(|| value_error("Hand has no winning tile."))?,
+        },
+        self_drawn,
+        seat_wind: seat_wind.map_or(Tile::EAST, |tile| tile.0),
+        round_wind: round_wind.map_or(Tile::EAST, |tile| tile.0),
+    };
+    hand::score(&tiles, &[], &win, rules)
+        .map(PyScore)
+        .map_err(value_error)
+}
+
+/// The `mahjong_tiles` Python module.
+#[pymodule(name = "mahjong_tiles")]
+fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyTile>()?;
+    module.add_class::<PyTileCategory>()?;
+    module.add_class::<PyScore>()?;
+    module.add_function(wrap_pyfunction!(parse_hand, module)?)?;
+    module.add_function(wrap_pyfunction!(format_hand, module)?)?;
+    module.add_function(wrap_pyfunction!(shanten, module)?)?;
+    module.add_function(wrap_pyfunction!(waits, module)?)?;
+    module.add_function(wrap_pyfunction!(score, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    /// Run Python code against the module, failing the test if it raises.
+    fn run(code: &std::ffi::CStr) {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "mahjong_tiles").unwrap();
+            python_module(&module).unwrap();
+            py.import("sys")
+                .and_then(|sys| sys.getattr("modules"))
+                .and_then(|modules| modules.set_item("mahjong_tiles", module))
+                .unwrap();
+            let globals = PyDict::new(py);
+            py.run(code, Some(&globals), None)
+                .unwrap_or_else(|error| panic!("{error}"));
+        });
+    }
+
+    #[test]
+    fn tile() {
+        run(cr#"
+from mahjong_tiles import Tile
+tile = Tile("5p")
+assert repr(tile) == "Tile('五筒')", repr(tile)
+assert tile == Tile("五筒") == Tile.from_id(13) == Tile.from_unicode("🀝")
+assert tile.category.name == "tong" and tile.value == 5
+assert tile.svg_name == "tong-5"
+assert tile.svg_data("light").startswith("data:image/svg+xml;base64,")
+"#);
+    }
+
+    #[test]
+    fn hash_and_order() {
+        let expected = hash(&Tile::TONG(5));
+        run(&std::ffi::CString::new(format!(
+            r#"
+from mahjong_tiles import Tile
+assert hash(Tile("5p")) == {}
+assert len({{Tile("5p"), Tile("五筒"), Tile("1m")}}) == 2
+assert sorted([Tile("東"), Tile("5p"), Tile("1m")]) == [Tile("1m"), Tile("5p"), Tile("東")]
+assert Tile("1m") < Tile("5p") and Tile("5p") != 13
+"#,
+            expected as i64
+        ))
+        .unwrap());
+    }
+
+    #[test]
+    fn hand() {
+        run(cr#"
+from mahjong_tiles import Tile, format_hand, parse_hand, shanten, waits
+hand = parse_hand("23m456p789s11155z")
+assert format_hand(hand) == "23m456p789s11155z"
+assert shanten(hand) == 0
+assert waits(hand) == [Tile("1m"), Tile("4m")]
+try:
+    parse_hand("8z")
+    raise AssertionError("8z should not parse")
+except ValueError:
+    pass
+"#);
+    }
+
+    #[test]
+    fn score() {
+        run(cr#"
+from mahjong_tiles import Tile, parse_hand, score
+result = score(parse_hand("234m456p678s88p234s"), "riichi", seat_wind=Tile("南"))
+assert result.patterns == [("Pinfu", 1), ("All simples", 1)], result
+assert (result.value, result.fu, result.points) == (2, 30, 2000)
+result = score(parse_hand("123m456m789m234m99m"), "hk", win=Tile("2m"), self_drawn=True)
+assert result.value == 10 and result.fu is None, result
+try:
+    score(parse_hand("123m456p789s1122z"), "zung-jung")
+    raise AssertionError("zung-jung should not be accepted")
+except ValueError:
+    pass
+"#);
+    }
+}
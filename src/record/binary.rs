@@ -0,0 +1,473 @@
+//! Compact binary serialisation of [`GameRecord`].
+//!
+//! The layout is a 4-byte `MJGR` header and a version byte, followed by the players,
+//! their starting scores and the events. Tiles are single bytes as given by
+//! [`Tile::id`], seats are single bytes, and all integers are little-endian.
+
+use super::{GameEvent, GameRecord, RecordError};
+use crate::models::{Claim, KongKind, Meld, MeldKind, Tile};
+
+const HEADER: &[u8; 4] = b"MJGR";
+const VERSION: u8 = 1;
+
+const TAG_START_ROUND: u8 = 0;
+const TAG_DRAW: u8 = 1;
+const TAG_DISCARD: u8 = 2;
+const TAG_MELD: u8 = 3;
+const TAG_FLOWER: u8 = 4;
+const TAG_WIN: u8 = 5;
+const TAG_EXHAUSTIVE_DRAW: u8 = 6;
+
+/// Append-only writer for the binary format.
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.0.push(value);
+    }
+
+    fn count(&mut self, count: usize) -> Result<(), RecordError> {
+        self.u8(u8::try_from(count).map_err(|_| RecordError::TooLong(count))?);
+        Ok(())
+    }
+
+    fn u32(&mut self, value: usize) -> Result<(), RecordError> {
+        let value = u32::try_from(value).map_err(|_| RecordError::TooLong(value))?;
+        self.0.extend(value.to_le_bytes());
+        Ok(())
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.0.extend(value.to_le_bytes());
+    }
+
+    fn tile(&mut self, tile: &Tile) -> Result<(), RecordError> {
+        self.u8(tile
+            .checked_id()
+            .ok_or(RecordError::InvalidTileValue(*tile))?);
+        Ok(())
+    }
+
+    fn tiles(&mut self, tiles: &[Tile]) -> Result<(), RecordError> {
+        self.count(tiles.len())?;
+        tiles.iter().try_for_each(|tile| self.tile(tile))
+    }
+
+    fn scores(&mut self, scores: &[i32]) -> Result<(), RecordError> {
+        self.count(scores.len())?;
+        scores.iter().for_each(|score| self.i32(*score));
+        Ok(())
+    }
+
+    fn meld(&mut self, meld: &Meld) -> Result<(), RecordError> {
+        self.u8(match meld.kind() {
+            MeldKind::CHOW => 0,
+            MeldKind::PUNG => 1,
+            MeldKind::KONG(KongKind::EXPOSED) => 2,
+            MeldKind::KONG(KongKind::CONCEALED) => 3,
+            MeldKind::KONG(KongKind::ADDED) => 4,
+        });
+        self.tiles(meld.tiles())?;
+        match meld.claim() {
+            Some(claim) => {
+                self.u8(1);
+                self.tile(&claim.tile)?;
+                self.u8(claim.from);
+            }
+            None => self.u8(0),
+        }
+        Ok(())
+    }
+}
+
+/// Cursor over the binary format.
+struct Reader<'b> {
+    bytes: &'b [u8],
+}
+
+impl<'b> Reader<'b> {
+    fn take(&mut self, count: usize) -> Result<&'b [u8], RecordError> {
+        if self.bytes.len() < count {
+            return Err(RecordError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, RecordError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<usize, RecordError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    }
+
+    fn i32(&mut self) -> Result<i32, RecordError> {
+        let bytes = self.take(4)?;
+        Ok(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn tile(&mut self) -> Result<Tile, RecordError> {
+        let id = self.u8()?;
+        Tile::from_id(id).ok_or(RecordError::InvalidTile(id))
+    }
+
+    fn tiles(&mut self) -> Result<Vec<Tile>, RecordError> {
+        let count = self.u8()?;
+        (0..count).map(|_| self.tile()).collect()
+    }
+
+    fn scores(&mut self) -> Result<Vec<i32>, RecordError> {
+        let count = self.u8()?;
+        (0..count).map(|_| self.i32()).collect()
+    }
+
+    fn meld(&mut self) -> Result<Meld, RecordError> {
+        let kind = match self.u8()? {
+            0 => MeldKind::CHOW,
+            1 => MeldKind::PUNG,
+            2 => MeldKind::KONG(KongKind::EXPOSED),
+            3 => MeldKind::KONG(KongKind::CONCEALED),
+            4 => MeldKind::KONG(KongKind::ADDED),
+            _ => return Err(RecordError::InvalidMeld),
+        };
+        let tiles = self.tiles()?;
+        let claim = match self.u8()? {
+            0 => None,
+            1 => Some(Claim {
+                tile: self.tile()?,
+                from: self.u8()?,
+            }),
+            _ => return Err(RecordError::InvalidMeld),
+        };
+
+        Meld::new(kind, tiles, claim).ok_or(RecordError::InvalidMeld)
+    }
+
+    fn event(&mut self) -> Result<GameEvent, RecordError> {
+        Ok(match self.u8()? {
+            TAG_START_ROUND => GameEvent::StartRound {
+                wind: self.tile()?,
+                dealer: self.u8()?,
+                hands: {
+                    let count = self.u8()?;
+                    (0..count).map(|_| self.tiles()).collect::<Result<_, _>>()?
+                },
+            },
+            TAG_DRAW => GameEvent::Draw {
+                seat: self.u8()?,
+                tile: self.tile()?,
+            },
+            TAG_DISCARD => GameEvent::Discard {
+                seat: self.u8()?,
+                tile: self.tile()?,
+            },
+            TAG_MELD => GameEvent::Meld {
+                seat: self.u8()?,
+                meld: self.meld()?,
+            },
+            TAG_FLOWER => GameEvent::Flower {
+                seat: self.u8()?,
+                tile: self.tile()?,
+            },
+            TAG_WIN => GameEvent::Win {
+                seat: self.u8()?,
+                from: self.u8()?,
+                tile: self.tile()?,
+                deltas: self.scores()?,
+            },
+            TAG_EXHAUSTIVE_DRAW => GameEvent::ExhaustiveDraw {
+                deltas: self.scores()?,
+            },
+            tag => return Err(RecordError::InvalidEvent(tag)),
+        })
+    }
+}
+
+impl GameRecord {
+    /// Encode this record in the compact binary format.
+    ///
+    /// Fails if the record holds an invalid tile, or more players, tiles or scores in one
+    /// place than the format can count.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, RecordError> {
+        let mut writer = Writer(HEADER.to_vec());
+        writer.u8(VERSION);
+
+        writer.count(self.players.len())?;
+        for name in &self.players {
+            writer.u32(name.len())?;
+            writer.0.extend(name.as_bytes());
+        }
+        writer.scores(&self.starting_scores)?;
+
+        writer.u32(self.events.len())?;
+        self.events.iter().try_for_each(|event| match event {
+            GameEvent::StartRound {
+                wind,
+                dealer,
+                hands,
+            } => {
+                writer.u8(TAG_START_ROUND);
+                writer.tile(wind)?;
+                writer.u8(*dealer);
+                writer.count(hands.len())?;
+                hands.iter().try_for_each(|hand| writer.tiles(hand))
+            }
+            GameEvent::Draw { seat, tile } => {
+                writer.u8(TAG_DRAW);
+                writer.u8(*seat);
+                writer.tile(tile)
+            }
+            GameEvent::Discard { seat, tile } => {
+                writer.u8(TAG_DISCARD);
+                writer.u8(*seat);
+                writer.tile(tile)
+            }
+            GameEvent::Meld { seat, meld } => {
+                writer.u8(TAG_MELD);
+                writer.u8(*seat);
+                writer.meld(meld)
+            }
+            GameEvent::Flower { seat, tile } => {
+                writer.u8(TAG_FLOWER);
+                writer.u8(*seat);
+                writer.tile(tile)
+            }
+            GameEvent::Win {
+                seat,
+                from,
+                tile,
+                deltas,
+            } => {
+                writer.u8(TAG_WIN);
+                writer.u8(*seat);
+                writer.u8(*from);
+                writer.tile(tile)?;
+                writer.scores(deltas)
+            }
+            GameEvent::ExhaustiveDraw { deltas } => {
+                writer.u8(TAG_EXHAUSTIVE_DRAW);
+                writer.scores(deltas)
+            }
+        })?;
+
+        Ok(writer.0)
+    }
+
+    /// Decode a record from the compact binary format.
+    ///
+    /// Fails with [`RecordError::TrailingBytes`] if any data follows the last event.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RecordError> {
+        let mut reader = Reader { bytes };
+
+        if reader.take(HEADER.len()).ok() != Some(HEADER.as_slice()) {
+            return Err(RecordError::InvalidHeader);
+        }
+        match reader.u8()? {
+            VERSION => {}
+            version => return Err(RecordError::UnsupportedVersion(version)),
+        }
+
+        let player_count = reader.u8()?;
+        let players = (0..player_count)
+            .map(|_| {
+                let length = reader.u32()?;
+                String::from_utf8(reader.take(length)?.to_vec())
+                    .map_err(|_| RecordError::InvalidName)
+            })
+            .collect::<Result<_, _>>()?;
+        let starting_scores = reader.scores()?;
+
+        let event_count = reader.u32()?;
+        let events = (0..event_count)
+            .map(|_| reader.event())
+            .collect::<Result<_, _>>()?;
+        if !reader.bytes.is_empty() {
+            return Err(RecordError::TrailingBytes(reader.bytes.len()));
+        }
+
+        Ok(Self {
+            players,
+            starting_scores,
+            events,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> GameRecord {
+        GameRecord {
+            players: vec!["東家".to_string(), "South".to_string()],
+            starting_scores: vec![25000, -300],
+            events: vec![
+                GameEvent::StartRound {
+                    wind: Tile::EAST,
+                    dealer: 1,
+                    hands: vec![
+                        vec![Tile::WAN(1), Tile::TONG(5), Tile::CENTRAL],
+                        vec![Tile::TIAO(9), Tile::PLUM],
+                    ],
+                },
+                GameEvent::Flower {
+                    seat: 1,
+                    tile: Tile::PLUM,
+                },
+                GameEvent::Draw {
+                    seat: 1,
+                    tile: Tile::CENTRAL,
+                },
+                GameEvent::Discard {
+                    seat: 1,
+                    tile: Tile::CENTRAL,
+                },
+                GameEvent::Meld {
+                    seat: 0,
+                    meld: Meld::pung(Tile::CENTRAL, 1).unwrap(),
+                },
+                GameEvent::Meld {
+                    seat: 0,
+                    meld: Meld::kong(Tile::WAN(1), KongKind::CONCEALED, 0).unwrap(),
+                },
+                GameEvent::Win {
+                    seat: 0,
+                    from: 0,
+                    tile: Tile::TONG(5),
+                    deltas: vec![1000, -1000],
+                },
+                GameEvent::ExhaustiveDraw { deltas: vec![0, 0] },
+            ],
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let record = sample();
+        assert_eq!(
+            GameRecord::from_bytes(&record.to_bytes().unwrap()).unwrap(),
+            record
+        );
+    }
+
+    #[test]
+    fn empty_roundtrip() {
+        let record = GameRecord::default();
+        assert_eq!(
+            GameRecord::from_bytes(&record.to_bytes().unwrap()).unwrap(),
+            record
+        );
+    }
+
+    #[test]
+    fn invalid_header() {
+        assert!(matches!(
+            GameRecord::from_bytes(b"JSON"),
+            Err(RecordError::InvalidHeader)
+        ));
+    }
+
+    #[test]
+    fn unsupported_version() {
+        assert!(matches!(
+            GameRecord::from_bytes(b"MJGR\x02"),
+            Err(RecordError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = sample().to_bytes().unwrap();
+        assert!(matches!(
+            GameRecord::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(RecordError::UnexpectedEnd)
+        ));
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        let mut bytes = sample().to_bytes().unwrap();
+        bytes.extend_from_slice(&sample().to_bytes().unwrap());
+
+        assert!(matches!(
+            GameRecord::from_bytes(&bytes),
+            Err(RecordError::TrailingBytes(count)) if count == bytes.len() / 2
+        ));
+    }
+
+    #[test]
+    fn invalid_tile() {
+        let mut bytes = GameRecord {
+            events: vec![GameEvent::Draw {
+                seat: 0,
+                tile: Tile::EAST,
+            }],
+            ..Default::default()
+        }
+        .to_bytes()
+        .unwrap();
+        *bytes.last_mut().unwrap() = Tile::KINDS;
+
+        assert!(matches!(
+            GameRecord::from_bytes(&bytes),
+            Err(RecordError::InvalidTile(Tile::KINDS))
+        ));
+    }
+
+    #[test]
+    fn invalid_tile_value() {
+        let record = GameRecord {
+            events: vec![GameEvent::Draw {
+                seat: 0,
+                tile: Tile::TONG(0),
+            }],
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            record.to_bytes(),
+            Err(RecordError::InvalidTileValue(Tile::TONG(0)))
+        ));
+    }
+
+    #[test]
+    fn too_long() {
+        let record = GameRecord {
+            players: vec![String::new(); 256],
+            ..Default::default()
+        };
+        assert!(matches!(record.to_bytes(), Err(RecordError::TooLong(256))));
+
+        let record = GameRecord {
+            events: vec![GameEvent::ExhaustiveDraw {
+                deltas: vec![0; 300],
+            }],
+            ..Default::default()
+        };
+        assert!(matches!(record.to_bytes(), Err(RecordError::TooLong(300))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_error_source() {
+        use std::error::Error;
+
+        let error = GameRecord::from_json("{").unwrap_err();
+        assert!(error
+            .source()
+            .is_some_and(|source| source.is::<serde_json::Error>()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_roundtrip() {
+        let record = sample();
+        assert_eq!(
+            GameRecord::from_json(&record.to_json().unwrap()).unwrap(),
+            record
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Errors raised while decoding or replaying game records.
+
+use crate::models::Tile;
+
+/// Errors raised while encoding or decoding a [`GameRecord`](super::GameRecord).
+#[derive(Debug)]
+pub enum RecordError {
+    /// The data does not start with the expected header.
+    InvalidHeader,
+    /// The data was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// The data ended before the record was complete.
+    UnexpectedEnd,
+    /// The given number of bytes remain after the last event of the record.
+    TrailingBytes(usize),
+    /// A tile identifier is out of range.
+    InvalidTile(u8),
+    /// A number tile to encode is valued outside `1` to `9`.
+    InvalidTileValue(Tile),
+    /// A list or name to encode is longer than the format can count.
+    TooLong(usize),
+    /// An event tag is not recognised.
+    InvalidEvent(u8),
+    /// The tiles of a meld do not form a valid meld.
+    InvalidMeld,
+    /// A player name is not valid UTF-8.
+    InvalidName,
+    /// The record is not valid JSON.
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for RecordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Data is not a game record."),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Game record version {version} is not supported.")
+            }
+            Self::UnexpectedEnd => write!(f, "Game record ended unexpectedly."),
+            Self::TrailingBytes(count) => {
+                write!(f, "{count} bytes remain after the end of the game record.")
+            }
+            Self::InvalidTile(id) => write!(f, "{id} is not a valid tile identifier."),
+            Self::InvalidTileValue(tile) => write!(f, "{tile:?} is not a valid tile."),
+            Self::TooLong(length) => {
+                write!(f, "Length {length} is too long for a game record.")
+            }
+            Self::InvalidEvent(tag) => write!(f, "{tag} is not a valid event tag."),
+            Self::InvalidMeld => write!(f, "Game record contains an invalid meld."),
+            Self::InvalidName => write!(f, "Player name is not valid UTF-8."),
+            #[cfg(feature = "serde")]
+            Self::Json(err) => write!(f, "Invalid game record JSON: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RecordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "serde")]
+            Self::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for RecordError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+/// Errors raised when the events of a record are inconsistent with the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The event refers to a seat that is not in the game.
+    InvalidSeat(u8),
+    /// The event happens before any round has started.
+    NoRound,
+    /// The player does not hold the tile.
+    TileNotInHand {
+        /// The seat of the player.
+        seat: u8,
+        /// The missing tile.
+        tile: Tile,
+    },
+    /// The meld claims a tile that is not the latest discard.
+    InvalidClaim {
+        /// The seat of the claiming player.
+        seat: u8,
+        /// The claimed tile.
+        tile: Tile,
+    },
+    /// The meld declared by the player in the given seat has no tiles.
+    InvalidMeld(u8),
+    /// The added kong has no matching exposed pung.
+    NoPungToAdd {
+        /// The seat of the player.
+        seat: u8,
+        /// The added tile.
+        tile: Tile,
+    },
+    /// The requested position is past the end of the record.
+    OutOfRange(usize),
+    /// The requested turn is past the end of the record.
+    TurnOutOfRange(usize),
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSeat(seat) => write!(f, "Seat {seat} is not in the game."),
+            Self::NoRound => write!(f, "Event happened before the first round started."),
+            Self::TileNotInHand { seat, tile } => {
+                write!(f, "Seat {seat} does not hold {tile:?}.")
+            }
+            Self::InvalidClaim { seat, tile } => {
+                write!(
+                    f,
+                    "Seat {seat} claimed {tile:?}, which is not the latest discard."
+                )
+            }
+            Self::InvalidMeld(seat) => write!(f, "Seat {seat} declared an invalid meld."),
+            Self::NoPungToAdd { seat, tile } => {
+                write!(f, "Seat {seat} has no exposed pung of {tile:?} to add to.")
+            }
+            Self::OutOfRange(position) => {
+                write!(f, "Position {position} is past the end of the record.")
+            }
+            Self::TurnOutOfRange(turn) => {
+                write!(f, "Turn {turn} is past the end of the record.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
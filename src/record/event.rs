@@ -0,0 +1,93 @@
+//! The events making up a game record.
+
+use crate::models::{Meld, Tile};
+
+/// A single event in a game.
+///
+/// Seats are numbered from `0` in the order of [`GameRecord::players`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum GameEvent {
+    /// Start of a round, with the starting hand of every player.
+    StartRound {
+        /// The prevailing wind.
+        wind: Tile,
+        /// The seat of the dealer.
+        dealer: u8,
+        /// The starting hands, in seat order.
+        hands: Vec<Vec<Tile>>,
+    },
+    /// A player draws a tile from the wall.
+    Draw {
+        /// The seat of the player.
+        seat: u8,
+        /// The tile drawn.
+        tile: Tile,
+    },
+    /// A player discards a tile.
+    Discard {
+        /// The seat of the player.
+        seat: u8,
+        /// The tile discarded.
+        tile: Tile,
+    },
+    /// A player declares a meld.
+    ///
+    /// The claimed tile, if any, is taken from the latest discard; added kongs take the
+    /// added tile from the hand and upgrade the existing pung.
+    Meld {
+        /// The seat of the player.
+        seat: u8,
+        /// The declared meld.
+        meld: Meld,
+    },
+    /// A player sets aside a flower or season from their hand.
+    Flower {
+        /// The seat of the player.
+        seat: u8,
+        /// The flower or season.
+        tile: Tile,
+    },
+    /// A player wins the round.
+    Win {
+        /// The seat of the winning player.
+        seat: u8,
+        /// The seat of the player who dealt in; equal to `seat` for a self-drawn win.
+        from: u8,
+        /// The winning tile.
+        tile: Tile,
+        /// The score changes of each seat.
+        deltas: Vec<i32>,
+    },
+    /// The round ends without a winner.
+    ExhaustiveDraw {
+        /// The score changes of each seat.
+        deltas: Vec<i32>,
+    },
+}
+
+/// A complete record of a game, independent of any external log format.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    /// The names of the players, in seat order.
+    pub players: Vec<String>,
+    /// The scores of the players at the start of the game, in seat order.
+    pub starting_scores: Vec<i32>,
+    /// The events of the game, in order.
+    pub events: Vec<GameEvent>,
+}
+
+#[cfg(feature = "serde")]
+impl GameRecord {
+    /// Decode a record from JSON.
+    pub fn from_json(json: &str) -> Result<Self, super::RecordError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Encode this record as JSON.
+    pub fn to_json(&self) -> Result<String, super::RecordError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
@@ -0,0 +1,44 @@
+//! Native game records, and a [`Replay`] cursor to step through them.
+//!
+//! A [`GameRecord`] is a flat list of [`GameEvent`]s. It can be stored in a compact
+//! binary form with [`GameRecord::to_bytes`], or as JSON with the `serde` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mahjong_tiles::Tile;
+//! use mahjong_tiles::record::{GameEvent, GameRecord, Replay};
+//!
+//! let record = GameRecord {
+//!     players: vec!["A".to_string(), "B".to_string()],
+//!     starting_scores: vec![25000, 25000],
+//!     events: vec![
+//!         GameEvent::StartRound {
+//!             wind: Tile::EAST,
+//!             dealer: 0,
+//!             hands: vec![vec![Tile::WAN(1), Tile::WAN(2)], vec![Tile::EAST, Tile::EAST]],
+//!         },
+//!         GameEvent::Draw { seat: 0, tile: Tile::WAN(3) },
+//!         GameEvent::Discard { seat: 0, tile: Tile::WAN(1) },
+//!     ],
+//! };
+//!
+//! let bytes = record.to_bytes().unwrap();
+//! assert_eq!(GameRecord::from_bytes(&bytes).unwrap(), record);
+//!
+//! let mut replay = Replay::new(&record);
+//! replay.seek_turn(1).unwrap();
+//! assert_eq!(replay.player(0).unwrap().hand, vec![Tile::WAN(2), Tile::WAN(3)]);
+//! assert_eq!(replay.player(0).unwrap().discards[0].tile, Tile::WAN(1));
+//! ```
+
+mod binary;
+
+mod error;
+pub use error::*;
+
+mod event;
+pub use event::*;
+
+mod replay;
+pub use replay::*;
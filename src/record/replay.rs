@@ -0,0 +1,604 @@
+//! Step-through replay of a [`GameRecord`].
+
+use super::{GameEvent, GameRecord, ReplayError};
+use crate::models::{KongKind, Meld, MeldKind, Tile};
+
+/// A discarded tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Discard {
+    /// The tile discarded.
+    pub tile: Tile,
+    /// Whether another player has claimed the tile into a meld.
+    pub claimed: bool,
+}
+
+/// The state of a single player at a point in the replay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerState {
+    /// The concealed tiles in the hand, sorted by [`Tile::id`].
+    pub hand: Vec<Tile>,
+    /// The declared melds, in order of declaration.
+    pub melds: Vec<Meld>,
+    /// The discards, in order of discarding.
+    pub discards: Vec<Discard>,
+    /// The flowers and seasons set aside.
+    pub flowers: Vec<Tile>,
+    /// The current score.
+    pub score: i32,
+}
+
+impl PlayerState {
+    /// Remove a tile from the concealed hand.
+    fn take(&mut self, seat: u8, tile: Tile) -> Result<(), ReplayError> {
+        let index = self
+            .hand
+            .iter()
+            .position(|held| *held == tile)
+            .ok_or(ReplayError::TileNotInHand { seat, tile })?;
+        self.hand.remove(index);
+        Ok(())
+    }
+
+    /// Remove several tiles from the concealed hand, leaving it unchanged if any is
+    /// missing.
+    fn take_all(&mut self, seat: u8, tiles: &[Tile]) -> Result<(), ReplayError> {
+        let mut hand = self.hand.clone();
+        for tile in tiles {
+            let index = hand
+                .iter()
+                .position(|held| held == tile)
+                .ok_or(ReplayError::TileNotInHand { seat, tile: *tile })?;
+            hand.remove(index);
+        }
+        self.hand = hand;
+        Ok(())
+    }
+
+    /// Add a tile to the concealed hand, keeping it sorted.
+    fn give(&mut self, tile: Tile) {
+        let index = self.hand.partition_point(|held| *held <= tile);
+        self.hand.insert(index, tile);
+    }
+}
+
+/// A cursor over a [`GameRecord`] that reconstructs the table at any point.
+///
+/// Positions count the events applied, from `0` before the first event to
+/// [`Replay::len`] after the last; turns count the discards made.
+#[derive(Debug, Clone)]
+pub struct Replay<'r> {
+    record: &'r GameRecord,
+    position: usize,
+    turn: usize,
+    players: Vec<PlayerState>,
+    last_discard: Option<(u8, Tile)>,
+    in_round: bool,
+}
+
+impl<'r> Replay<'r> {
+    /// Create a replay positioned before the first event.
+    pub fn new(record: &'r GameRecord) -> Self {
+        let players = (0..record.players.len())
+            .map(|seat| PlayerState {
+                score: record
+                    .starting_scores
+                    .get(seat)
+                    .copied()
+                    .unwrap_or_default(),
+                ..Default::default()
+            })
+            .collect();
+
+        Self {
+            record,
+            position: 0,
+            turn: 0,
+            players,
+            last_discard: None,
+            in_round: false,
+        }
+    }
+
+    /// Returns the number of events in the record.
+    pub fn len(&self) -> usize {
+        self.record.events.len()
+    }
+
+    /// Returns whether the record has no events.
+    pub fn is_empty(&self) -> bool {
+        self.record.events.is_empty()
+    }
+
+    /// Returns the number of events applied so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of discards made so far.
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    /// Returns the state of every player, in seat order.
+    pub fn players(&self) -> &[PlayerState] {
+        &self.players
+    }
+
+    /// Returns the state of the player in the given seat.
+    pub fn player(&self, seat: u8) -> Option<&PlayerState> {
+        self.players.get(seat as usize)
+    }
+
+    /// Returns the tiles the player in the given seat can see: their own hand, and
+    /// every player's unclaimed discards, exposed melds and flowers.
+    pub fn visible_tiles(&self, seat: u8) -> Vec<Tile> {
+        let own_hand = self
+            .player(seat)
+            .map(|player| player.hand.as_slice())
+            .unwrap_or_default();
+
+        self.players
+            .iter()
+            .enumerate()
+            .flat_map(|(index, player)| {
+                let is_own = index == seat as usize;
+                player
+                    .melds
+                    .iter()
+                    .filter(move |meld| is_own || !meld.is_concealed())
+                    .flat_map(|meld| meld.tiles().iter().copied())
+                    .chain(
+                        player
+                            .discards
+                            .iter()
+                            .filter(|discard| !discard.claimed)
+                            .map(|discard| discard.tile),
+                    )
+                    .chain(player.flowers.iter().copied())
+            })
+            .chain(own_hand.iter().copied())
+            .collect()
+    }
+
+    /// Apply the next event, returning it; returns [`None`] at the end of the record.
+    pub fn step(&mut self) -> Result<Option<&'r GameEvent>, ReplayError> {
+        let Some(event) = self.record.events.get(self.position) else {
+            return Ok(None);
+        };
+        self.apply(event)?;
+        self.position += 1;
+        Ok(Some(event))
+    }
+
+    /// Move to the given position, replaying from the start if moving backwards.
+    pub fn seek(&mut self, position: usize) -> Result<(), ReplayError> {
+        if position > self.len() {
+            return Err(ReplayError::OutOfRange(position));
+        }
+        if position < self.position {
+            *self = Self::new(self.record);
+        }
+        while self.position < position {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    /// Move to just after the given number of discards.
+    pub fn seek_turn(&mut self, turn: usize) -> Result<(), ReplayError> {
+        if turn <= self.turn {
+            *self = Self::new(self.record);
+        }
+        while self.turn < turn {
+            if self.step()?.is_none() {
+                return Err(ReplayError::TurnOutOfRange(turn));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get a mutable reference to the player in the given seat, during a round.
+    fn player_mut(&mut self, seat: u8) -> Result<&mut PlayerState, ReplayError> {
+        if !self.in_round {
+            return Err(ReplayError::NoRound);
+        }
+        self.players
+            .get_mut(seat as usize)
+            .ok_or(ReplayError::InvalidSeat(seat))
+    }
+
+    /// Apply a single event to the table.
+    fn apply(&mut self, event: &GameEvent) -> Result<(), ReplayError> {
+        match event {
+            GameEvent::StartRound { hands, .. } => {
+                if hands.len() > self.players.len() {
+                    return Err(ReplayError::InvalidSeat(
+                        u8::try_from(self.players.len()).unwrap_or(u8::MAX),
+                    ));
+                }
+                self.players
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(seat, player)| {
+                        *player = PlayerState {
+                            score: player.score,
+                            ..Default::default()
+                        };
+                        hands
+                            .get(seat)
+                            .into_iter()
+                            .flatten()
+                            .for_each(|tile| player.give(*tile));
+                    });
+                self.last_discard = None;
+                self.in_round = true;
+            }
+            GameEvent::Draw { seat, tile } => {
+                self.player_mut(*seat)?.give(*tile);
+            }
+            GameEvent::Discard { seat, tile } => {
+                let player = self.player_mut(*seat)?;
+                player.take(*seat, *tile)?;
+                player.discards.push(Discard {
+                    tile: *tile,
+                    claimed: false,
+                });
+                self.last_discard = Some((*seat, *tile));
+                self.turn += 1;
+            }
+            GameEvent::Meld { seat, meld } => self.apply_meld(*seat, meld)?,
+            GameEvent::Flower { seat, tile } => {
+                let player = self.player_mut(*seat)?;
+                player.take(*seat, *tile)?;
+                player.flowers.push(*tile);
+            }
+            GameEvent::Win { deltas, .. } | GameEvent::ExhaustiveDraw { deltas } => {
+                self.players
+                    .iter_mut()
+                    .zip(deltas)
+                    .for_each(|(player, delta)| player.score += delta);
+                self.last_discard = None;
+                self.in_round = false;
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply a meld declaration to the table, leaving it unchanged if the meld cannot be
+    /// declared.
+    fn apply_meld(&mut self, seat: u8, meld: &Meld) -> Result<(), ReplayError> {
+        if meld.kind() == MeldKind::KONG(KongKind::ADDED) {
+            let tile = *meld.tiles().first().ok_or(ReplayError::InvalidMeld(seat))?;
+            let player = self.player_mut(seat)?;
+            let index = player
+                .melds
+                .iter()
+                .position(|existing| {
+                    existing.kind() == MeldKind::PUNG && existing.tiles().first() == Some(&tile)
+                })
+                .ok_or(ReplayError::NoPungToAdd { seat, tile })?;
+            player.take(seat, tile)?;
+            player.melds[index] = meld.clone();
+            return Ok(());
+        }
+
+        let mut from_hand = meld.tiles().to_vec();
+        if let Some(claim) = meld.claim() {
+            let is_latest_discard = self.last_discard == Some((claim.from, claim.tile));
+            let index = from_hand
+                .iter()
+                .position(|tile| *tile == claim.tile)
+                .filter(|_| is_latest_discard)
+                .ok_or(ReplayError::InvalidClaim {
+                    seat,
+                    tile: claim.tile,
+                })?;
+            from_hand.remove(index);
+        }
+
+        let player = self.player_mut(seat)?;
+        player.take_all(seat, &from_hand)?;
+        player.melds.push(meld.clone());
+
+        if let Some(claim) = meld.claim() {
+            if let Some(discard) = self
+                .players
+                .get_mut(usize::from(claim.from))
+                .and_then(|player| player.discards.last_mut())
+            {
+                discard.claimed = true;
+            }
+            self.last_discard = None;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::Claim;
+
+    fn sample() -> GameRecord {
+        GameRecord {
+            players: ["A", "B", "C"].map(String::from).to_vec(),
+            starting_scores: vec![100, 100, 100],
+            events: vec![
+                GameEvent::StartRound {
+                    wind: Tile::EAST,
+                    dealer: 0,
+                    hands: vec![
+                        vec![Tile::TONG(4), Tile::WAN(2), Tile::EAST, Tile::PLUM],
+                        vec![Tile::WAN(3), Tile::WAN(4), Tile::NORTH],
+                        vec![Tile::CENTRAL, Tile::CENTRAL, Tile::CENTRAL],
+                    ],
+                },
+                GameEvent::Flower {
+                    seat: 0,
+                    tile: Tile::PLUM,
+                },
+                GameEvent::Draw {
+                    seat: 0,
+                    tile: Tile::TONG(1),
+                },
+                GameEvent::Discard {
+                    seat: 0,
+                    tile: Tile::WAN(2),
+                },
+                GameEvent::Meld {
+                    seat: 1,
+                    meld: Meld::chow(
+                        [Tile::WAN(2), Tile::WAN(3), Tile::WAN(4)],
+                        Claim {
+                            tile: Tile::WAN(2),
+                            from: 0,
+                        },
+                    )
+                    .unwrap(),
+                },
+                GameEvent::Discard {
+                    seat: 1,
+                    tile: Tile::NORTH,
+                },
+                GameEvent::Draw {
+                    seat: 2,
+                    tile: Tile::CENTRAL,
+                },
+                GameEvent::Meld {
+                    seat: 2,
+                    meld: Meld::kong(Tile::CENTRAL, KongKind::CONCEALED, 2).unwrap(),
+                },
+                GameEvent::Draw {
+                    seat: 2,
+                    tile: Tile::TONG(4),
+                },
+                GameEvent::Win {
+                    seat: 2,
+                    from: 2,
+                    tile: Tile::TONG(4),
+                    deltas: vec![-10, -10, 20],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn full_replay() {
+        let record = sample();
+        let mut replay = Replay::new(&record);
+        replay.seek(replay.len()).unwrap();
+
+        assert_eq!(replay.turn(), 2);
+        assert_eq!(
+            replay
+                .players()
+                .iter()
+                .map(|player| player.score)
+                .collect::<Vec<_>>(),
+            vec![90, 90, 120]
+        );
+
+        let east = replay.player(0).unwrap();
+        assert_eq!(east.hand, vec![Tile::TONG(1), Tile::TONG(4), Tile::EAST]);
+        assert_eq!(east.flowers, vec![Tile::PLUM]);
+        assert_eq!(
+            east.discards,
+            vec![Discard {
+                tile: Tile::WAN(2),
+                claimed: true
+            }]
+        );
+
+        let south = replay.player(1).unwrap();
+        assert!(south.hand.is_empty());
+        assert_eq!(south.melds.len(), 1);
+
+        let west = replay.player(2).unwrap();
+        assert_eq!(west.hand, vec![Tile::TONG(4)]);
+        assert!(west.melds[0].is_concealed());
+    }
+
+    #[test]
+    fn seek_backwards() {
+        let record = sample();
+        let mut replay = Replay::new(&record);
+        replay.seek(replay.len()).unwrap();
+        replay.seek_turn(1).unwrap();
+
+        assert_eq!(replay.position(), 4);
+        assert_eq!(
+            replay.player(0).unwrap().discards,
+            vec![Discard {
+                tile: Tile::WAN(2),
+                claimed: false
+            }]
+        );
+        assert_eq!(replay.player(1).unwrap().hand.len(), 3);
+
+        replay.seek(0).unwrap();
+        assert!(replay.player(0).unwrap().hand.is_empty());
+        assert_eq!(replay.player(0).unwrap().score, 100);
+    }
+
+    #[test]
+    fn visible_tiles() {
+        let record = sample();
+        let mut replay = Replay::new(&record);
+        replay.seek(8).unwrap();
+
+        let mut visible = replay.visible_tiles(0);
+        visible.sort_by_key(Tile::id);
+        assert_eq!(
+            visible,
+            vec![
+                Tile::WAN(2),
+                Tile::WAN(3),
+                Tile::WAN(4),
+                Tile::TONG(1),
+                Tile::TONG(4),
+                Tile::EAST,
+                Tile::NORTH,
+                Tile::PLUM,
+            ]
+        );
+        assert_eq!(
+            replay
+                .visible_tiles(2)
+                .iter()
+                .filter(|tile| **tile == Tile::CENTRAL)
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let record = sample();
+        let mut replay = Replay::new(&record);
+
+        assert_eq!(replay.seek(100), Err(ReplayError::OutOfRange(100)));
+        assert_eq!(replay.seek_turn(3), Err(ReplayError::TurnOutOfRange(3)));
+    }
+
+    macro_rules! create_test {
+        ($name:ident, $events:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let record = GameRecord {
+                    players: vec!["A".to_string(), "B".to_string()],
+                    starting_scores: vec![0, 0],
+                    events: $events,
+                };
+                let mut replay = Replay::new(&record);
+                assert_eq!(replay.seek(record.events.len()), Err($expected));
+            }
+        };
+    }
+
+    fn start() -> GameEvent {
+        GameEvent::StartRound {
+            wind: Tile::EAST,
+            dealer: 0,
+            hands: vec![vec![Tile::EAST, Tile::EAST], vec![Tile::WAN(1)]],
+        }
+    }
+
+    create_test!(
+        no_round,
+        vec![GameEvent::Draw {
+            seat: 0,
+            tile: Tile::EAST
+        }],
+        ReplayError::NoRound
+    );
+    create_test!(
+        invalid_seat,
+        vec![
+            start(),
+            GameEvent::Draw {
+                seat: 2,
+                tile: Tile::EAST
+            }
+        ],
+        ReplayError::InvalidSeat(2)
+    );
+    create_test!(
+        tile_not_in_hand,
+        vec![
+            start(),
+            GameEvent::Discard {
+                seat: 1,
+                tile: Tile::EAST
+            }
+        ],
+        ReplayError::TileNotInHand {
+            seat: 1,
+            tile: Tile::EAST
+        }
+    );
+    create_test!(
+        claim_without_discard,
+        vec![
+            start(),
+            GameEvent::Meld {
+                seat: 0,
+                meld: Meld::pung(Tile::EAST, 1).unwrap()
+            }
+        ],
+        ReplayError::InvalidClaim {
+            seat: 0,
+            tile: Tile::EAST
+        }
+    );
+    create_test!(
+        add_without_pung,
+        vec![
+            start(),
+            GameEvent::Meld {
+                seat: 0,
+                meld: Meld::kong(Tile::EAST, KongKind::ADDED, 1).unwrap()
+            }
+        ],
+        ReplayError::NoPungToAdd {
+            seat: 0,
+            tile: Tile::EAST
+        }
+    );
+
+    #[test]
+    fn failed_claim_leaves_table_unchanged() {
+        let record = GameRecord {
+            players: vec!["A".to_string(), "B".to_string()],
+            starting_scores: vec![0, 0],
+            events: vec![
+                GameEvent::StartRound {
+                    wind: Tile::EAST,
+                    dealer: 0,
+                    hands: vec![vec![Tile::EAST], vec![Tile::EAST]],
+                },
+                GameEvent::Discard {
+                    seat: 0,
+                    tile: Tile::EAST,
+                },
+                GameEvent::Meld {
+                    seat: 1,
+                    meld: Meld::pung(Tile::EAST, 0).unwrap(),
+                },
+            ],
+        };
+        let mut replay = Replay::new(&record);
+        replay.seek(2).unwrap();
+
+        assert_eq!(
+            replay.step(),
+            Err(ReplayError::TileNotInHand {
+                seat: 1,
+                tile: Tile::EAST
+            })
+        );
+        assert_eq!(replay.player(1).unwrap().hand, vec![Tile::EAST]);
+        assert!(replay.player(1).unwrap().melds.is_empty());
+        assert!(!replay.player(0).unwrap().discards[0].claimed);
+        assert_eq!(replay.last_discard, Some((0, Tile::EAST)));
+    }
+}
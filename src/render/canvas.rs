@@ -0,0 +1,127 @@
+//! Low level accumulation of tile images into an SVG document.
+
+use super::{tile_elements, Layout, Placement, TileDecoration};
+use crate::models::Tile;
+use crate::svg::SvgStyle;
+
+/// Accumulates tile images left to right, in groups, and tracks the drawn extent.
+///
+/// Tiles sit on a common baseline at `y = 0`, so their tops have negative `y`.
+pub(crate) struct Canvas {
+    style: SvgStyle,
+    layout: Layout,
+    cursor: f64,
+    last_rotated: Option<f64>,
+    top: f64,
+    right: f64,
+    groups: Vec<String>,
+    current: Vec<String>,
+}
+
+impl Canvas {
+    /// Create an empty canvas.
+    pub fn new(style: SvgStyle, layout: Layout) -> Self {
+        Self {
+            style,
+            layout,
+            cursor: 0.0,
+            last_rotated: None,
+            top: 0.0,
+            right: 0.0,
+            groups: Vec::new(),
+            current: Vec::new(),
+        }
+    }
+
+    /// Draw a tile with the top left corner of its bounding box at the given position,
+    /// turned 90° clockwise if requested.
+    fn place(&mut self, tile: Option<Tile>, x: f64, y: f64, rotated: bool) {
+        let Layout { tile_width, .. } = self.layout;
+        let tile_height = self.layout.tile_height();
+
+        let (transform, width) = if rotated {
+            (
+                format!("translate({} {y}) rotate(90)", x + tile_height),
+                tile_height,
+            )
+        } else {
+            (format!("translate({x} {y})"), tile_width)
+        };
+        let name = tile
+            .and_then(|tile| tile.svg_name())
+            .map(|name| format!(r#" data-tile="{name}""#))
+            .unwrap_or_default();
+
+        self.current.push(format!(
+            r#"<g transform="{transform}"{name}>{}</g>"#,
+            tile_elements(
+                tile,
+                self.style,
+                &TileDecoration::default(),
+                tile_width,
+                tile_height
+            )
+        ));
+        self.top = self.top.min(y);
+        self.right = self.right.max(x + width);
+    }
+
+    /// Draw a tile according to its placement, advancing the cursor as needed.
+    pub fn draw(&mut self, placement: Placement) {
+        let Layout {
+            tile_width,
+            tile_spacing,
+            ..
+        } = self.layout;
+        let tile_height = self.layout.tile_height();
+
+        match placement {
+            Placement::UPRIGHT(tile) => {
+                self.place(Some(tile), self.cursor, -tile_height, false);
+                self.cursor += tile_width + tile_spacing;
+            }
+            Placement::FACEDOWN => {
+                self.place(None, self.cursor, -tile_height, false);
+                self.cursor += tile_width + tile_spacing;
+            }
+            Placement::ROTATED(tile) => {
+                self.place(Some(tile), self.cursor, -tile_width, true);
+                self.last_rotated = Some(self.cursor);
+                self.cursor += tile_height + tile_spacing;
+            }
+            Placement::STACKED(tile) => match self.last_rotated {
+                Some(x) => self.place(Some(tile), x, -2.0 * tile_width - tile_spacing, true),
+                None => self.draw(Placement::ROTATED(tile)),
+            },
+        }
+    }
+
+    /// Close the tiles drawn since the last group into a group of the given class.
+    pub fn end_group(&mut self, class: &str) {
+        self.last_rotated = None;
+        if self.current.is_empty() {
+            return;
+        }
+
+        self.groups.push(format!(
+            r#"<g class="{class}">{}</g>"#,
+            self.current.drain(..).collect::<String>()
+        ));
+        self.cursor += self.layout.group_spacing - self.layout.tile_spacing;
+    }
+
+    /// Finish the document, sized to fit everything drawn plus padding.
+    pub fn finish(mut self) -> String {
+        self.end_group("tiles");
+
+        let padding = self.layout.padding;
+        let (x, y) = (-padding, self.top - padding);
+        let width = self.right + 2.0 * padding;
+        let height = -self.top + 2.0 * padding;
+
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="{x} {y} {width} {height}">{}</svg>"#,
+            self.groups.concat()
+        )
+    }
+}
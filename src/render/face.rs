@@ -0,0 +1,258 @@
+//! Composition of a single tile from its base and glyph layers.
+
+use crate::models::Tile;
+use crate::svg::{BaseLayer, HasSvgData, SvgStyle};
+
+/// The width of the tile artwork, in its own user units.
+const ARTWORK_WIDTH: f64 = 300.0;
+/// The height of the tile artwork, in its own user units.
+const ARTWORK_HEIGHT: f64 = 400.0;
+/// The corner radius of the tile artwork, in its own user units.
+const ARTWORK_CORNER_RADIUS: f64 = 40.0;
+
+/// Optional effects applied when composing a tile; colours are any SVG colour value,
+/// and are escaped when written into the markup.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TileDecoration {
+    /// Colour multiplied into the base layer, e.g. to tell each team's tile backs apart.
+    pub tint: Option<String>,
+    /// Colour washed translucently over the whole tile.
+    pub highlight: Option<String>,
+    /// Colour of an outline drawn just inside the edge of the tile.
+    pub outline: Option<String>,
+}
+
+/// Escape text for an XML attribute value.
+fn escape(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut escaped, c| {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                _ => escaped.push(c),
+            }
+            escaped
+        })
+}
+
+/// Returns the id of the filter for a tint, unique to the colour so that tiles with
+/// different tints can be inlined into one document.
+fn tint_id(tint: &str) -> String {
+    tint.bytes().fold("tint-".to_string(), |mut id, byte| {
+        id.push_str(&format!("{byte:02x}"));
+        id
+    })
+}
+
+/// Returns the elements drawing a tile, or its back if `tile` is [`None`], scaled to
+/// the given size with the top left corner at the origin.
+///
+/// Tiles without a glyph in this style are drawn as a blank face with a text label.
+pub(crate) fn tile_elements(
+    tile: Option<Tile>,
+    style: SvgStyle,
+    decoration: &TileDecoration,
+    width: f64,
+    height: f64,
+) -> String {
+    let image = |data: &str| format!(r#"<image width="{width}" height="{height}" href="{data}"/>"#);
+    let scale = width / ARTWORK_WIDTH;
+    let radius = ARTWORK_CORNER_RADIUS * scale;
+    let mut elements = String::new();
+
+    let base = if tile.is_some() {
+        BaseLayer::FRONT
+    } else {
+        BaseLayer::BACK
+    };
+    match &decoration.tint {
+        Some(tint) => elements.push_str(&format!(
+            concat!(
+                r#"<filter id="{id}"><feFlood flood-color="{tint}"/>"#,
+                r#"<feComposite in2="SourceAlpha" operator="in"/>"#,
+                r#"<feBlend in2="SourceGraphic" mode="multiply"/></filter>"#,
+                r#"<g filter="url(#{id})">{base}</g>"#,
+            ),
+            id = tint_id(tint),
+            tint = escape(tint),
+            base = image(style.base_layer(base)),
+        )),
+        None => elements.push_str(&image(style.base_layer(base))),
+    }
+
+    if let Some(tile) = tile {
+        match tile.svg_data(style) {
+            Some(data) => elements.push_str(&image(data)),
+            None => elements.push_str(&format!(
+                r#"<text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                width / 2.0,
+                height / 2.0,
+                width / 2.0,
+                tile.chinese_name().unwrap_or("?"),
+            )),
+        }
+    }
+
+    if let Some(highlight) = &decoration.highlight {
+        let highlight = escape(highlight);
+        elements.push_str(&format!(
+            r#"<rect width="{width}" height="{height}" rx="{radius}" fill="{highlight}" fill-opacity="0.4"/>"#
+        ));
+    }
+    if let Some(outline) = &decoration.outline {
+        let outline = escape(outline);
+        let stroke = width * 0.04;
+        elements.push_str(&format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}" fill="none" stroke="{outline}" stroke-width="{stroke}"/>"#,
+            stroke / 2.0,
+            stroke / 2.0,
+            width - stroke,
+            height - stroke,
+            radius - stroke / 2.0,
+        ));
+    }
+
+    elements
+}
+
+/// Compose a whole tile, or its back if `tile` is [`None`], into a single SVG document.
+///
+/// The glyph from [`HasSvgData::svg_data`] is drawn over [`BaseLayer::FRONT`], or
+/// [`BaseLayer::BACK`] is drawn alone, and the decoration is applied on top.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::{SvgStyle, Tile};
+/// use mahjong_tiles::render::{compose_tile, TileDecoration};
+///
+/// let back = compose_tile(
+///     None,
+///     SvgStyle::LIGHT,
+///     &TileDecoration {
+///         tint: Some("#3070ff".to_string()),
+///         ..Default::default()
+///     },
+/// );
+/// assert!(back.contains(r##"flood-color="#3070ff""##));
+///
+/// let selected = compose_tile(
+///     Some(Tile::TONG(5)),
+///     SvgStyle::LIGHT,
+///     &TileDecoration {
+///         outline: Some("gold".to_string()),
+///         ..Default::default()
+///     },
+/// );
+/// assert_eq!(selected.matches("<image").count(), 2);
+/// ```
+pub fn compose_tile(tile: Option<Tile>, style: SvgStyle, decoration: &TileDecoration) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{ARTWORK_WIDTH}" height="{ARTWORK_HEIGHT}" viewBox="0 0 {ARTWORK_WIDTH} {ARTWORK_HEIGHT}">{}</svg>"#,
+        tile_elements(tile, style, decoration, ARTWORK_WIDTH, ARTWORK_HEIGHT)
+    )
+}
+
+#[cfg(all(test, feature = "theme-dark"))]
+mod test {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $tile:expr, $decoration:expr, $images:literal, $contains:expr) => {
+            #[test]
+            fn $name() {
+                let svg = compose_tile($tile, SvgStyle::DARK, &$decoration);
+
+                assert_eq!(svg.matches("<image").count(), $images);
+                $contains
+                    .iter()
+                    .for_each(|expected: &&str| assert!(svg.contains(expected), "{expected}"));
+            }
+        };
+    }
+
+    fn decoration(
+        tint: Option<&str>,
+        highlight: Option<&str>,
+        outline: Option<&str>,
+    ) -> TileDecoration {
+        TileDecoration {
+            tint: tint.map(String::from),
+            highlight: highlight.map(String::from),
+            outline: outline.map(String::from),
+        }
+    }
+
+    create_test!(
+        face,
+        Some(Tile::WAN(3)),
+        TileDecoration::default(),
+        2,
+        [
+            SvgStyle::DARK.base_layer(BaseLayer::FRONT),
+            Tile::WAN(3).svg_data(SvgStyle::DARK).unwrap(),
+        ]
+    );
+    create_test!(
+        back,
+        None,
+        TileDecoration::default(),
+        1,
+        [SvgStyle::DARK.base_layer(BaseLayer::BACK)]
+    );
+    create_test!(
+        tinted_back,
+        None,
+        decoration(Some("red"), None, None),
+        1,
+        [
+            r#"<filter id="tint-726564">"#,
+            r#"flood-color="red""#,
+            r#"filter="url(#tint-726564)""#
+        ]
+    );
+    create_test!(
+        escaped,
+        Some(Tile::EAST),
+        decoration(Some(r#"red" onload="alert(1)"#), Some("<b>"), Some("a'b&c")),
+        2,
+        [
+            r#"flood-color="red&quot; onload=&quot;alert(1)""#,
+            r#"fill="&lt;b&gt;""#,
+            r#"stroke="a&apos;b&amp;c""#
+        ]
+    );
+
+    #[test]
+    fn tints_have_distinct_filters() {
+        let red = compose_tile(None, SvgStyle::DARK, &decoration(Some("red"), None, None));
+        let blue = compose_tile(None, SvgStyle::DARK, &decoration(Some("blue"), None, None));
+
+        assert!(red.contains(r#"url(#tint-726564)"#));
+        assert!(blue.contains(r#"url(#tint-626c7565)"#));
+    }
+    create_test!(
+        highlighted,
+        Some(Tile::EAST),
+        decoration(None, Some("yellow"), None),
+        2,
+        [r#"fill="yellow""#]
+    );
+    create_test!(
+        outlined,
+        Some(Tile::EAST),
+        decoration(None, None, Some("blue")),
+        2,
+        [r#"stroke="blue" stroke-width="12""#]
+    );
+    create_test!(
+        flower,
+        Some(Tile::SPRING),
+        TileDecoration::default(),
+        2,
+        [Tile::SPRING.svg_data(SvgStyle::DARK).unwrap()]
+    );
+}
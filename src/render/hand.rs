@@ -0,0 +1,167 @@
+//! Rendering of a player's hand.
+
+use super::{Canvas, Layout, Placement};
+use crate::models::{Meld, Tile};
+use crate::svg::SvgStyle;
+
+/// Render a hand and its melds into a single SVG document.
+///
+/// The concealed tiles come first, in the order given, followed by each meld as a
+/// separate group laid out by [`Placement::for_meld`]. Every tile image is embedded,
+/// so the document is self-contained.
+pub fn render_hand(hand: &[Tile], melds: &[Meld], style: SvgStyle, layout: Layout) -> String {
+    let hand = hand
+        .iter()
+        .copied()
+        .map(Placement::UPRIGHT)
+        .collect::<Vec<_>>();
+
+    render(&hand, melds, None, style, layout)
+}
+
+/// Render the hand of the player in `seat` into a single SVG document.
+///
+/// Unlike [`render_hand`], the concealed tiles are given as placements, so that an
+/// opponent's hand can be drawn with [`Placement::FACEDOWN`]; claimed tiles are
+/// turned towards the player they were claimed from.
+pub fn render_seated_hand(
+    hand: &[Placement],
+    melds: &[Meld],
+    seat: u8,
+    style: SvgStyle,
+    layout: Layout,
+) -> String {
+    render(hand, melds, Some(seat), style, layout)
+}
+
+/// Draw the concealed tiles and the melds, each as its own group.
+fn render(
+    hand: &[Placement],
+    melds: &[Meld],
+    seat: Option<u8>,
+    style: SvgStyle,
+    layout: Layout,
+) -> String {
+    let mut canvas = Canvas::new(style, layout);
+
+    hand.iter().for_each(|placement| canvas.draw(*placement));
+    canvas.end_group("hand");
+
+    melds.iter().for_each(|meld| {
+        Placement::for_meld(meld, seat)
+            .into_iter()
+            .for_each(|placement| canvas.draw(placement));
+        canvas.end_group("meld");
+    });
+
+    canvas.finish()
+}
+
+#[cfg(all(test, feature = "theme-dark", feature = "theme-light"))]
+mod test {
+    use super::*;
+    use crate::models::KongKind;
+
+    fn layout() -> Layout {
+        Layout {
+            tile_width: 30.0,
+            tile_spacing: 1.0,
+            group_spacing: 10.0,
+            padding: 2.0,
+        }
+    }
+
+    macro_rules! create_test {
+        ($name:ident, $hand:expr, $melds:expr, $images:literal, $size:literal) => {
+            #[test]
+            fn $name() {
+                let svg = render_hand(&$hand, &$melds, SvgStyle::DARK, layout());
+
+                assert_eq!(svg.matches("<image").count(), $images);
+                assert!(
+                    svg.starts_with(&format!(
+                        r#"<svg xmlns="http://www.w3.org/2000/svg" {}"#,
+                        $size
+                    )),
+                    "{}",
+                    &svg[..200.min(svg.len())]
+                );
+            }
+        };
+    }
+
+    create_test!(empty, [], [], 0, r#"width="4" height="4""#);
+    create_test!(single, [Tile::EAST], [], 2, r#"width="34" height="44""#);
+    create_test!(
+        three,
+        [Tile::TONG(1), Tile::TONG(2), Tile::TONG(3)],
+        [],
+        6,
+        r#"width="96" height="44""#
+    );
+    create_test!(
+        with_melds,
+        [Tile::TONG(1)],
+        [
+            Meld::pung(Tile::CENTRAL, 1).unwrap(),
+            Meld::kong(Tile::WAN(9), KongKind::EXPOSED, 2).unwrap()
+        ],
+        16,
+        r#"width="289" height="44""#
+    );
+    create_test!(
+        added_kong,
+        [],
+        [Meld::kong(Tile::WAN(9), KongKind::ADDED, 2).unwrap()],
+        8,
+        r#"width="106" height="65""#
+    );
+    create_test!(
+        concealed_kong,
+        [],
+        [Meld::kong(Tile::WAN(9), KongKind::CONCEALED, 0).unwrap()],
+        6,
+        r#"width="127" height="44""#
+    );
+
+    #[test]
+    fn groups() {
+        let svg = render_hand(
+            &[Tile::TONG(1), Tile::TONG(2)],
+            &[Meld::pung(Tile::CENTRAL, 1).unwrap()],
+            SvgStyle::LIGHT,
+            layout(),
+        );
+
+        assert_eq!(svg.matches(r#"<g class="hand">"#).count(), 1);
+        assert_eq!(svg.matches(r#"<g class="meld">"#).count(), 1);
+        assert_eq!(svg.matches(r#"data-tile="honour-central""#).count(), 3);
+        assert_eq!(svg.matches("rotate(90)").count(), 1);
+    }
+
+    #[test]
+    fn face_down() {
+        // The dark theme draws its backs the same as its fronts, so they can't be told
+        // apart once minified.
+        let back = SvgStyle::LIGHT.get_svg_by_name("base-back").unwrap();
+        let svg = render_seated_hand(
+            &[Placement::FACEDOWN; 4],
+            &[Meld::kong(Tile::EAST, KongKind::CONCEALED, 0).unwrap()],
+            0,
+            SvgStyle::LIGHT,
+            layout(),
+        );
+
+        assert_eq!(svg.matches(back).count(), 6);
+        assert_eq!(svg.matches(r#"data-tile="honour-east""#).count(), 2);
+    }
+
+    #[test]
+    fn flowers() {
+        let svg = render_hand(&[Tile::PLUM, Tile::WINTER], &[], SvgStyle::LIGHT, layout());
+
+        assert_eq!(svg.matches("<image").count(), 4);
+        assert!(svg.contains(r#"data-tile="flower-plum""#));
+        assert!(svg.contains(r#"data-tile="season-winter""#));
+    }
+}
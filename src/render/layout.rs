@@ -0,0 +1,33 @@
+/// The aspect ratio of the tile artwork, as height over width.
+pub const TILE_ASPECT_RATIO: f64 = 4.0 / 3.0;
+
+/// Dimensions used when composing tiles into an image, in SVG user units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Layout {
+    /// The width of a single upright tile; the height follows [`TILE_ASPECT_RATIO`].
+    pub tile_width: f64,
+    /// The gap between adjacent tiles in the same group.
+    pub tile_spacing: f64,
+    /// The gap between the concealed hand and each meld.
+    pub group_spacing: f64,
+    /// The margin around the whole image.
+    pub padding: f64,
+}
+
+impl Layout {
+    /// Returns the height of a single upright tile.
+    pub fn tile_height(&self) -> f64 {
+        self.tile_width * TILE_ASPECT_RATIO
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            tile_width: 60.0,
+            tile_spacing: 2.0,
+            group_spacing: 20.0,
+            padding: 4.0,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Composition of multiple tiles into a single SVG image, or into text.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mahjong_tiles::{Meld, SvgStyle, Tile};
+//! use mahjong_tiles::render::{render_hand, Layout};
+//!
+//! let hand = [Tile::WAN(1), Tile::WAN(2), Tile::WAN(3), Tile::EAST];
+//! let melds = [Meld::pung(Tile::CENTRAL, 2).unwrap()];
+//!
+//! let svg = render_hand(&hand, &melds, SvgStyle::LIGHT, Layout::default());
+//! assert!(svg.starts_with("<svg"));
+//! assert_eq!(svg.matches("<image").count(), 14);
+//! ```
+
+mod canvas;
+pub(crate) use canvas::*;
+
+mod face;
+pub use face::*;
+
+mod hand;
+pub use hand::*;
+
+mod layout;
+pub use layout::*;
+
+mod placement;
+pub use placement::*;
+
+mod sprite;
+pub use sprite::*;
+
+mod terminal;
+pub use terminal::*;
+
+#[cfg(feature = "raster")]
+mod raster;
+#[cfg(feature = "raster")]
+pub use raster::*;
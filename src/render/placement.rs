@@ -0,0 +1,194 @@
+use crate::models::{KongKind, Meld, MeldKind, Tile};
+
+/// How a single tile is placed on the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// Face up and upright.
+    UPRIGHT(Tile),
+    /// Face up and turned sideways, as for a claimed tile.
+    ROTATED(Tile),
+    /// Face up and sideways, on top of the preceding [`Placement::ROTATED`] tile, as
+    /// for the tile added to a kong.
+    STACKED(Tile),
+    /// Face down and upright, as for concealed tiles.
+    FACEDOWN,
+}
+
+impl Placement {
+    /// Returns the placements to draw a meld owned by the player in `seat`.
+    ///
+    /// The claimed tile is turned sideways, on the left if claimed from the previous
+    /// player, in the middle if from the player opposite, and on the right if from the
+    /// next player. Without a `seat`, it is put on the left. Concealed kongs show the
+    /// two middle tiles face up, and other melds without a claim are all upright.
+    pub fn for_meld(meld: &Meld, seat: Option<u8>) -> Vec<Self> {
+        let mut tiles = meld.tiles().to_vec();
+        tiles.sort_by_key(|tile| tile.value());
+
+        let Some(claim) = meld.claim() else {
+            let is_concealed = meld.kind() == MeldKind::KONG(KongKind::CONCEALED);
+            let last = tiles.len() - 1;
+            return tiles
+                .into_iter()
+                .enumerate()
+                .map(|(index, tile)| match index {
+                    0 if is_concealed => Self::FACEDOWN,
+                    _ if is_concealed && index == last => Self::FACEDOWN,
+                    _ => Self::UPRIGHT(tile),
+                })
+                .collect();
+        };
+
+        let is_added = meld.kind() == MeldKind::KONG(KongKind::ADDED);
+        let claimed = tiles
+            .iter()
+            .position(|tile| *tile == claim.tile)
+            .expect("Meld should contain its claimed tile.");
+        tiles.remove(claimed);
+        let added = is_added.then(|| tiles.pop()).flatten();
+
+        let mut placements: Vec<Self> = tiles.into_iter().map(Self::UPRIGHT).collect();
+        let index = match seat.map(|seat| (claim.from % 4 + 4 - seat % 4) % 4) {
+            Some(2) => 1,
+            Some(1) => placements.len(),
+            _ => 0,
+        };
+        placements.insert(index, Self::ROTATED(claim.tile));
+        if let Some(tile) = added {
+            placements.insert(index + 1, Self::STACKED(tile));
+        }
+
+        placements
+    }
+}
+
+#[cfg(test)]
+mod test_for_meld {
+    use super::*;
+    use crate::models::Claim;
+
+    macro_rules! create_test {
+        ($name:ident, $meld:expr, $seat:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(Placement::for_meld(&$meld.unwrap(), $seat), $expected);
+            }
+        };
+    }
+
+    use Placement::*;
+
+    create_test!(
+        chow_from_left,
+        Meld::chow(
+            [Tile::TONG(4), Tile::TONG(3), Tile::TONG(5)],
+            Claim {
+                tile: Tile::TONG(4),
+                from: 3
+            }
+        ),
+        Some(0),
+        vec![
+            ROTATED(Tile::TONG(4)),
+            UPRIGHT(Tile::TONG(3)),
+            UPRIGHT(Tile::TONG(5))
+        ]
+    );
+    create_test!(
+        pung_from_opposite,
+        Meld::pung(Tile::EAST, 3),
+        Some(1),
+        vec![
+            UPRIGHT(Tile::EAST),
+            ROTATED(Tile::EAST),
+            UPRIGHT(Tile::EAST)
+        ]
+    );
+    create_test!(
+        pung_from_right,
+        Meld::pung(Tile::EAST, 2),
+        Some(1),
+        vec![
+            UPRIGHT(Tile::EAST),
+            UPRIGHT(Tile::EAST),
+            ROTATED(Tile::EAST)
+        ]
+    );
+    create_test!(
+        pung_unseated,
+        Meld::pung(Tile::EAST, 2),
+        None,
+        vec![
+            ROTATED(Tile::EAST),
+            UPRIGHT(Tile::EAST),
+            UPRIGHT(Tile::EAST)
+        ]
+    );
+    create_test!(
+        kong_exposed_from_right,
+        Meld::kong(Tile::WAN(2), KongKind::EXPOSED, 0),
+        Some(3),
+        vec![
+            UPRIGHT(Tile::WAN(2)),
+            UPRIGHT(Tile::WAN(2)),
+            UPRIGHT(Tile::WAN(2)),
+            ROTATED(Tile::WAN(2))
+        ]
+    );
+    create_test!(
+        kong_added_from_opposite,
+        Meld::kong(Tile::WAN(2), KongKind::ADDED, 2),
+        Some(0),
+        vec![
+            UPRIGHT(Tile::WAN(2)),
+            ROTATED(Tile::WAN(2)),
+            STACKED(Tile::WAN(2)),
+            UPRIGHT(Tile::WAN(2))
+        ]
+    );
+    create_test!(
+        kong_concealed,
+        Meld::kong(Tile::CENTRAL, KongKind::CONCEALED, 0),
+        Some(0),
+        vec![
+            FACEDOWN,
+            UPRIGHT(Tile::CENTRAL),
+            UPRIGHT(Tile::CENTRAL),
+            FACEDOWN
+        ]
+    );
+    create_test!(
+        pung_unclaimed,
+        Meld::new(MeldKind::PUNG, vec![Tile::EAST; 3], None),
+        Some(0),
+        vec![
+            UPRIGHT(Tile::EAST),
+            UPRIGHT(Tile::EAST),
+            UPRIGHT(Tile::EAST)
+        ]
+    );
+    create_test!(
+        chow_unclaimed,
+        Meld::new(
+            MeldKind::CHOW,
+            vec![Tile::TONG(4), Tile::TONG(3), Tile::TONG(5)],
+            None
+        ),
+        Some(0),
+        vec![
+            UPRIGHT(Tile::TONG(3)),
+            UPRIGHT(Tile::TONG(4)),
+            UPRIGHT(Tile::TONG(5))
+        ]
+    );
+    create_test!(
+        pung_from_large_seat,
+        Meld::pung(Tile::EAST, 255),
+        Some(1),
+        vec![
+            UPRIGHT(Tile::EAST),
+            ROTATED(Tile::EAST),
+            UPRIGHT(Tile::EAST)
+        ]
+    );
+}
@@ -0,0 +1,198 @@
+//! Rasterisation of tiles and composed images to PNG.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use resvg::{tiny_skia, usvg};
+
+use super::{compose_tile, TileDecoration};
+use crate::models::Tile;
+use crate::svg::{BaseLayer, SvgStyle};
+
+/// Errors raised while rasterising an SVG document.
+#[derive(Debug)]
+pub enum RasterError {
+    /// The SVG document could not be parsed.
+    InvalidSvg(usvg::Error),
+    /// The requested size is zero, or too large to allocate.
+    InvalidSize(u32),
+    /// The image could not be encoded as PNG.
+    Encoding(String),
+}
+
+impl std::fmt::Display for RasterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSvg(err) => write!(f, "Could not parse the SVG document: {err}"),
+            Self::InvalidSize(width) => write!(f, "Cannot rasterise to a width of {width}px."),
+            Self::Encoding(err) => write!(f, "Could not encode the PNG: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RasterError {}
+
+impl From<usvg::Error> for RasterError {
+    fn from(err: usvg::Error) -> Self {
+        Self::InvalidSvg(err)
+    }
+}
+
+/// Key of [`tile_cache`]: the SVG name, style and width of a rasterised tile.
+type TileKey = (&'static str, SvgStyle, u32);
+
+/// Tiles already rasterised by [`tile_to_png`].
+fn tile_cache() -> &'static Mutex<HashMap<TileKey, Arc<[u8]>>> {
+    static CACHE: OnceLock<Mutex<HashMap<TileKey, Arc<[u8]>>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// Rasterise an SVG document, such as one from [`render_hand`](super::render_hand),
+/// to PNG bytes `width` pixels wide.
+///
+/// The height follows the aspect ratio of the document. Text is not drawn, as no
+/// fonts are loaded.
+pub fn svg_to_png(svg: &str, width: u32) -> Result<Vec<u8>, RasterError> {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default())?;
+    let size = tree.size();
+    let scale = width as f32 / size.width();
+    let height = (size.height() * scale).round() as u32;
+
+    let mut pixmap =
+        tiny_skia::Pixmap::new(width, height).ok_or(RasterError::InvalidSize(width))?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .encode_png()
+        .map_err(|err| RasterError::Encoding(err.to_string()))
+}
+
+/// Rasterise a whole tile, or its back if `tile` is [`None`], to PNG bytes `width`
+/// pixels wide.
+///
+/// Results are cached for the lifetime of the process, keyed by SVG name, style and
+/// width, so repeated requests share the same bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::{SvgStyle, Tile};
+/// use mahjong_tiles::render::tile_to_png;
+///
+/// let png = tile_to_png(Some(Tile::TONG(5)), SvgStyle::LIGHT, 60).unwrap();
+/// assert!(png.starts_with(b"\x89PNG"));
+/// ```
+pub fn tile_to_png(
+    tile: Option<Tile>,
+    style: SvgStyle,
+    width: u32,
+) -> Result<Arc<[u8]>, RasterError> {
+    let name = match tile {
+        Some(tile) => tile.svg_name(),
+        None => Some(BaseLayer::BACK.svg_name()),
+    };
+    // Tiles with no SVG name are not valid, and are rendered without being cached.
+    let key = name.map(|name| (name, style, width));
+
+    if let Some(png) = key.as_ref().and_then(|key| {
+        tile_cache()
+            .lock()
+            .expect("Raster cache poisoned.")
+            .get(key)
+            .cloned()
+    }) {
+        return Ok(png);
+    }
+
+    let png: Arc<[u8]> = svg_to_png(
+        &compose_tile(tile, style, &TileDecoration::default()),
+        width,
+    )?
+    .into();
+    if let Some(key) = key {
+        tile_cache()
+            .lock()
+            .expect("Raster cache poisoned.")
+            .insert(key, png.clone());
+    }
+
+    Ok(png)
+}
+
+/// Drop every tile cached by [`tile_to_png`].
+pub fn clear_tile_png_cache() {
+    tile_cache().lock().expect("Raster cache poisoned.").clear();
+}
+
+#[cfg(all(test, feature = "theme-light"))]
+mod test {
+    use super::*;
+    use crate::models::Meld;
+    use crate::render::{render_hand, Layout};
+
+    /// Get the width and height from the header of a PNG.
+    fn png_size(png: &[u8]) -> (u32, u32) {
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        let dimension =
+            |offset: usize| u32::from_be_bytes(png[offset..offset + 4].try_into().unwrap());
+        (dimension(16), dimension(20))
+    }
+
+    #[test]
+    fn tile() {
+        let png = tile_to_png(Some(Tile::WAN(7)), SvgStyle::LIGHT, 90).unwrap();
+
+        assert_eq!(png_size(&png), (90, 120));
+    }
+
+    #[test]
+    fn back() {
+        let png = tile_to_png(None, SvgStyle::LIGHT, 30).unwrap();
+
+        assert_eq!(png_size(&png), (30, 40));
+    }
+
+    #[test]
+    fn cached() {
+        let first = tile_to_png(Some(Tile::SUMMER), SvgStyle::LIGHT, 45).unwrap();
+        let second = tile_to_png(Some(Tile::SUMMER), SvgStyle::LIGHT, 45).unwrap();
+        let other = tile_to_png(Some(Tile::SUMMER), SvgStyle::LIGHT, 46).unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+    }
+
+    #[test]
+    fn hand() {
+        let svg = render_hand(
+            &[Tile::TONG(1), Tile::TONG(2)],
+            &[Meld::pung(Tile::EAST, 1).unwrap()],
+            SvgStyle::LIGHT,
+            Layout::default(),
+        );
+        let (width, height) = png_size(&svg_to_png(&svg, 400).unwrap());
+
+        assert_eq!(width, 400);
+        assert!(height > 0 && height < width);
+    }
+
+    #[test]
+    fn zero_width() {
+        assert!(matches!(
+            tile_to_png(Some(Tile::EAST), SvgStyle::LIGHT, 0),
+            Err(RasterError::InvalidSize(0))
+        ));
+    }
+
+    #[test]
+    fn invalid_svg() {
+        assert!(matches!(
+            svg_to_png("not an svg", 10),
+            Err(RasterError::InvalidSvg(_))
+        ));
+    }
+}
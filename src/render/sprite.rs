@@ -0,0 +1,258 @@
+//! Packing of every tile of a style into a single sprite sheet.
+
+use super::{tile_elements, Layout, TileDecoration};
+use crate::models::Tile;
+use crate::svg::{BaseLayer, SvgStyle};
+
+/// The position of a single tile in a [`SpriteSheet`], in pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sprite {
+    /// The SVG name of the tile, or of [`BaseLayer::BACK`] for the tile back.
+    pub name: String,
+    /// The distance from the left edge of the sheet.
+    pub x: f64,
+    /// The distance from the top edge of the sheet.
+    pub y: f64,
+    /// The width of the tile.
+    pub width: f64,
+    /// The height of the tile.
+    pub height: f64,
+}
+
+/// Every kind of tile, and the tile back, packed into a grid in one SVG document.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::SvgStyle;
+/// use mahjong_tiles::render::{Layout, SpriteSheet};
+///
+/// let sheet = SpriteSheet::new(SvgStyle::LIGHT, Layout::default(), 9);
+/// let css = sheet.to_css("tiles.svg", "mahjong");
+///
+/// assert_eq!(sheet.sprites().len(), 43);
+/// assert!(css.contains(".mahjong-tong-5 { background-position: -252px -86px; }"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpriteSheet {
+    svg: String,
+    sprites: Vec<Sprite>,
+    width: f64,
+    height: f64,
+}
+
+impl SpriteSheet {
+    /// Pack the tiles of a style into rows of `columns` tiles, in the order of
+    /// [`Tile::id`] followed by the tile back.
+    ///
+    /// The tiles are spaced by [`Layout::tile_spacing`] inside [`Layout::padding`];
+    /// [`Layout::group_spacing`] is not used.
+    pub fn new(style: SvgStyle, layout: Layout, columns: usize) -> Self {
+        let columns = columns.max(1);
+        let tile_width = layout.tile_width;
+        let tile_height = layout.tile_height();
+        let tiles = (0..Tile::KINDS)
+            .filter_map(Tile::from_id)
+            .filter_map(|tile| Some((Some(tile), tile.svg_name()?)))
+            .chain([(None, BaseLayer::BACK.svg_name())])
+            .collect::<Vec<_>>();
+
+        let mut elements = String::new();
+        let sprites = tiles
+            .into_iter()
+            .enumerate()
+            .map(|(index, (tile, name))| {
+                let x =
+                    layout.padding + (index % columns) as f64 * (tile_width + layout.tile_spacing);
+                let y =
+                    layout.padding + (index / columns) as f64 * (tile_height + layout.tile_spacing);
+
+                elements.push_str(&format!(
+                    r#"<g id="{name}" transform="translate({x} {y})">{}</g>"#,
+                    tile_elements(
+                        tile,
+                        style,
+                        &TileDecoration::default(),
+                        tile_width,
+                        tile_height
+                    )
+                ));
+                Sprite {
+                    name: name.to_string(),
+                    x,
+                    y,
+                    width: tile_width,
+                    height: tile_height,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let rows = sprites.len().div_ceil(columns);
+        let width = 2.0 * layout.padding
+            + columns.min(sprites.len()) as f64 * (tile_width + layout.tile_spacing)
+            - layout.tile_spacing;
+        let height = 2.0 * layout.padding + rows as f64 * (tile_height + layout.tile_spacing)
+            - layout.tile_spacing;
+
+        Self {
+            svg: format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">{elements}</svg>"#
+            ),
+            sprites,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the sheet as an SVG document.
+    ///
+    /// Each tile is a group with its SVG name as `id`, so it can also be referenced
+    /// as a fragment of the sheet.
+    pub fn svg(&self) -> &str {
+        &self.svg
+    }
+
+    /// Returns the position of every tile in the sheet.
+    pub fn sprites(&self) -> &[Sprite] {
+        &self.sprites
+    }
+
+    /// Returns the position of the tile with the given SVG name.
+    pub fn sprite(&self, name: &str) -> Option<&Sprite> {
+        self.sprites.iter().find(|sprite| sprite.name == name)
+    }
+
+    /// Returns the width of the whole sheet.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Returns the height of the whole sheet.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Returns a JSON manifest of the sheet size and the position of each tile,
+    /// keyed by SVG name.
+    pub fn to_json(&self) -> String {
+        let sprites = self
+            .sprites
+            .iter()
+            .map(|sprite| {
+                format!(
+                    r#""{}":{{"x":{},"y":{},"width":{},"height":{}}}"#,
+                    sprite.name, sprite.x, sprite.y, sprite.width, sprite.height
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            r#"{{"width":{},"height":{},"sprites":{{{sprites}}}}}"#,
+            self.width, self.height
+        )
+    }
+
+    /// Returns CSS classes to draw each tile from the sheet at `url`.
+    ///
+    /// The class `prefix` sets the sheet as background and the tile size, and
+    /// `prefix-<svg name>` picks the tile, e.g. `class="mahjong mahjong-wan-1"`.
+    pub fn to_css(&self, url: &str, prefix: &str) -> String {
+        let (width, height) = self
+            .sprites
+            .first()
+            .map_or((0.0, 0.0), |sprite| (sprite.width, sprite.height));
+        let mut css = format!(
+            ".{prefix} {{ display: inline-block; width: {width}px; height: {height}px; background: url(\"{url}\") no-repeat; background-size: {}px {}px; }}\n",
+            self.width, self.height
+        );
+        self.sprites.iter().for_each(|sprite| {
+            css.push_str(&format!(
+                ".{prefix}-{} {{ background-position: -{}px -{}px; }}\n",
+                sprite.name, sprite.x, sprite.y
+            ))
+        });
+
+        css
+    }
+
+    /// Rasterise the sheet to PNG bytes, at one pixel per SVG user unit so that the
+    /// manifest coordinates still apply.
+    #[cfg(feature = "raster")]
+    pub fn to_png(&self) -> Result<Vec<u8>, super::RasterError> {
+        super::svg_to_png(&self.svg, self.width.ceil() as u32)
+    }
+}
+
+#[cfg(all(test, feature = "theme-dark"))]
+mod test {
+    use super::*;
+
+    fn layout() -> Layout {
+        Layout {
+            tile_width: 30.0,
+            tile_spacing: 1.0,
+            group_spacing: 10.0,
+            padding: 2.0,
+        }
+    }
+
+    #[test]
+    fn grid() {
+        let sheet = SpriteSheet::new(SvgStyle::DARK, layout(), 10);
+
+        assert_eq!(sheet.sprites().len(), Tile::KINDS as usize + 1);
+        assert_eq!((sheet.width(), sheet.height()), (313.0, 208.0));
+        assert_eq!(
+            sheet.sprite("tong-2"),
+            Some(&Sprite {
+                name: "tong-2".to_string(),
+                x: 2.0,
+                y: 43.0,
+                width: 30.0,
+                height: 40.0
+            })
+        );
+        assert_eq!(sheet.sprite("base-back").unwrap().x, 64.0);
+        assert_eq!(
+            sheet.svg().matches("<image").count(),
+            2 * Tile::KINDS as usize + 1
+        );
+    }
+
+    #[test]
+    fn single_row() {
+        let sheet = SpriteSheet::new(SvgStyle::DARK, layout(), 100);
+
+        assert_eq!((sheet.width(), sheet.height()), (1336.0, 44.0));
+    }
+
+    #[test]
+    fn json() {
+        let json = SpriteSheet::new(SvgStyle::DARK, layout(), 10).to_json();
+
+        assert!(json.starts_with(r#"{"width":313,"height":208,"sprites":{"wan-1":{"x":2,"y":2,"#));
+        assert!(json.contains(r#""season-winter":{"x":33,"y":166,"width":30,"height":40}"#));
+        assert!(json.ends_with("}}}"));
+    }
+
+    #[test]
+    fn css() {
+        let css = SpriteSheet::new(SvgStyle::DARK, layout(), 10).to_css("sheet.png", "tile");
+
+        assert!(css.starts_with(".tile { display: inline-block; width: 30px; height: 40px;"));
+        assert!(css.contains(r#"url("sheet.png")"#));
+        assert!(css.contains(".tile-honour-east { background-position: -219px -84px; }"));
+        assert_eq!(css.lines().count(), Tile::KINDS as usize + 2);
+    }
+
+    #[cfg(feature = "raster")]
+    #[test]
+    fn png() {
+        let png = SpriteSheet::new(SvgStyle::DARK, layout(), 10)
+            .to_png()
+            .unwrap();
+
+        assert_eq!(&png[16..24], &[0, 0, 1, 57, 0, 0, 0, 208]);
+    }
+}
@@ -0,0 +1,221 @@
+//! Rendering of hands as text, for terminals and logs.
+
+use super::Placement;
+use crate::models::{IsTile, Meld, Tile, TileCategory, UNICODE_TILE_BACK};
+
+/// How tiles are drawn as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextStyle {
+    /// One character per tile from the Unicode Mahjong Tiles block.
+    UNICODE,
+    /// Boxes of ASCII lines around the Chinese name of each tile, for terminals
+    /// without a font for the Mahjong Tiles block.
+    ASCII,
+}
+
+/// Returns the ANSI SGR colour code for a tile, by suit; winds use the default.
+fn ansi_colour(tile: Tile) -> Option<u8> {
+    match tile.category() {
+        TileCategory::WAN => Some(31),
+        TileCategory::TIAO => Some(32),
+        TileCategory::TONG => Some(34),
+        TileCategory::FLOWER(_) => Some(35),
+        TileCategory::HONOUR(_) => match tile {
+            Tile::CENTRAL => Some(31),
+            Tile::PROSPERITY => Some(32),
+            _ => None,
+        },
+    }
+}
+
+/// Wrap text in the ANSI colour of a tile, if `colour` is set.
+fn paint(text: &str, tile: Option<Tile>, colour: bool) -> String {
+    match tile.and_then(ansi_colour) {
+        Some(code) if colour => format!("\x1b[{code}m{text}\x1b[0m"),
+        _ => text.to_string(),
+    }
+}
+
+/// Pad the Chinese name of a tile, or `##` for a back or an invalid tile, to four
+/// terminal columns.
+fn ascii_label(tile: Option<Tile>) -> String {
+    match tile.and_then(|tile| tile.unique_name()) {
+        Some(name) => {
+            // Every character of the name is two columns wide.
+            format!("{name}{}", " ".repeat(4 - 2 * name.chars().count().min(2)))
+        }
+        None => "####".to_string(),
+    }
+}
+
+/// Render a hand and its melds as text.
+///
+/// [`TextStyle::UNICODE`] gives a single line, with a space after every tile as
+/// many fonts draw them wider than a column. [`TextStyle::ASCII`] gives three lines.
+/// In both, each meld follows as a separate group, with the claimed tile in the
+/// position given by [`Placement::for_meld`] and concealed tiles drawn as backs.
+/// If `colour` is set, tiles are coloured by suit with ANSI escape codes. Tiles that
+/// are not valid, see [`Tile::checked_id`], are drawn as backs.
+///
+/// # Example
+///
+/// ```rust
+/// use mahjong_tiles::{Meld, Tile};
+/// use mahjong_tiles::render::{render_hand_text, TextStyle};
+///
+/// let hand = [Tile::WAN(1), Tile::WAN(2), Tile::WAN(3)];
+/// let melds = [Meld::pung(Tile::EAST, 2).unwrap()];
+///
+/// assert_eq!(
+///     render_hand_text(&hand, &melds, TextStyle::UNICODE, false),
+///     "🀇 🀈 🀉   🀀 🀀 🀀 "
+/// );
+/// assert_eq!(
+///     render_hand_text(&hand[..1], &[], TextStyle::ASCII, false),
+///     "+----+\n|一万|\n+----+"
+/// );
+/// ```
+pub fn render_hand_text(hand: &[Tile], melds: &[Meld], style: TextStyle, colour: bool) -> String {
+    let hand = hand
+        .iter()
+        .map(|tile| tile.checked_id().map(|_| *tile))
+        .collect::<Vec<_>>();
+    let groups = std::iter::once(hand)
+        .chain(melds.iter().map(|meld| {
+            Placement::for_meld(meld, None)
+                .into_iter()
+                .map(|placement| match placement {
+                    Placement::UPRIGHT(tile)
+                    | Placement::ROTATED(tile)
+                    | Placement::STACKED(tile) => Some(tile),
+                    Placement::FACEDOWN => None,
+                })
+                .collect()
+        }))
+        .filter(|group| !group.is_empty())
+        .collect::<Vec<_>>();
+
+    match style {
+        TextStyle::UNICODE => groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|tile| {
+                        let c = tile
+                            .and_then(|tile| tile.to_unicode())
+                            .unwrap_or(UNICODE_TILE_BACK);
+                        paint(&format!("{c} "), *tile, colour)
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("  "),
+        TextStyle::ASCII => {
+            let border = groups
+                .iter()
+                .map(|group| format!("+{}", "----+".repeat(group.len())))
+                .collect::<Vec<_>>()
+                .join("  ");
+            let labels = groups
+                .iter()
+                .map(|group| {
+                    group.iter().fold("|".to_string(), |line, tile| {
+                        format!("{line}{}|", paint(&ascii_label(*tile), *tile, colour))
+                    })
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            [border.as_str(), labels.as_str(), border.as_str()].join("\n")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::KongKind;
+
+    macro_rules! create_test {
+        ($name:ident, $hand:expr, $melds:expr, $style:expr, $colour:literal, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(
+                    render_hand_text(&$hand, &$melds, $style, $colour),
+                    $expected
+                );
+            }
+        };
+    }
+
+    create_test!(empty, [], [], TextStyle::UNICODE, false, "");
+    create_test!(
+        unicode_invalid,
+        [Tile::TONG(1), Tile::TONG(10), Tile::WAN(0)],
+        [],
+        TextStyle::UNICODE,
+        true,
+        "\x1b[34m🀙 \x1b[0m🀫 🀫 "
+    );
+    create_test!(
+        ascii_invalid,
+        [Tile::TONG(10)],
+        [],
+        TextStyle::ASCII,
+        false,
+        "+----+\n|####|\n+----+"
+    );
+    create_test!(
+        unicode,
+        [Tile::TONG(1), Tile::TIAO(2), Tile::PLUM],
+        [],
+        TextStyle::UNICODE,
+        false,
+        "🀙 🀑 🀢 "
+    );
+    create_test!(
+        unicode_colour,
+        [Tile::WAN(1), Tile::EAST],
+        [],
+        TextStyle::UNICODE,
+        true,
+        "\x1b[31m🀇 \x1b[0m🀀 "
+    );
+    create_test!(
+        unicode_concealed_kong,
+        [],
+        [Meld::kong(Tile::CENTRAL, KongKind::CONCEALED, 0).unwrap()],
+        TextStyle::UNICODE,
+        false,
+        "🀫 🀄 🀄 🀫 "
+    );
+    create_test!(
+        ascii,
+        [Tile::TIAO(9), Tile::SOUTH],
+        [Meld::pung(Tile::PROSPERITY, 1).unwrap()],
+        TextStyle::ASCII,
+        false,
+        concat!(
+            "+----+----+  +----+----+----+\n",
+            "|九条|南  |  |发  |发  |发  |\n",
+            "+----+----+  +----+----+----+"
+        )
+    );
+    create_test!(
+        ascii_colour,
+        [Tile::TONG(2), Tile::NORTH],
+        [],
+        TextStyle::ASCII,
+        true,
+        "+----+----+\n|\x1b[34m二筒\x1b[0m|北  |\n+----+----+"
+    );
+    create_test!(
+        ascii_concealed_kong,
+        [],
+        [Meld::kong(Tile::WAN(5), KongKind::CONCEALED, 0).unwrap()],
+        TextStyle::ASCII,
+        false,
+        "+----+----+----+----+\n|####|五万|五万|####|\n+----+----+----+----+"
+    );
+}
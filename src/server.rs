@@ -0,0 +1,326 @@
+//! A local HTTP server for the embedded tile images, and hands rendered from them.
+//!
+//! | Path | Response |
+//! |------|----------|
+//! | `/tiles/{style}/{svg_name}.svg` | The SVG markup from [`SvgStyle::get_svg_markup_by_name`]. |
+//! | `/hand/{mpsz}.svg?style={style}` | The hand drawn by [`render_hand`]; the style defaults to the first embedded one. |
+//!
+//! Every image carries an `ETag`, the FNV-1a hash of its markup so that it is stable
+//! across builds, and requests with a matching `If-None-Match` are answered with
+//! `304 Not Modified`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mahjong_tiles::server::TileServer;
+//!
+//! let server = TileServer::default();
+//! let response = server.respond("GET", "/tiles/light/honour-east.svg", None);
+//! assert_eq!(response.status, 200);
+//!
+//! let etag = response.etag.unwrap();
+//! let cached = server.respond("GET", "/tiles/light/honour-east.svg", Some(&etag));
+//! assert_eq!(cached.status, 304);
+//! ```
+
+use std::io;
+use std::net::ToSocketAddrs;
+
+use crate::hand::parse_hand;
+use crate::render::{render_hand, Layout};
+use crate::svg::SvgStyle;
+
+/// A response to a request for a tile image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The value of the `Content-Type` header.
+    pub content_type: &'static str,
+    /// The value of the `ETag` header, quoted, for images.
+    pub etag: Option<String>,
+    /// The value of the `Allow` header, for `405 Method Not Allowed`.
+    pub allow: Option<&'static str>,
+    /// The response body, empty for `HEAD` requests and `304 Not Modified`.
+    pub body: Vec<u8>,
+}
+
+impl TileResponse {
+    /// A plain text response.
+    fn text(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            etag: None,
+            allow: None,
+            body: message.into().into_bytes(),
+        }
+    }
+
+    /// An SVG image, or `304 Not Modified` if the client already holds it.
+    fn svg(markup: &str, if_none_match: Option<&str>) -> Self {
+        let etag = format!("\"{:016x}\"", fnv1a(markup.as_bytes()));
+
+        let is_cached = if_none_match.is_some_and(|header| {
+            header
+                .split(',')
+                .map(|tag| tag.trim().trim_start_matches("W/"))
+                .any(|tag| tag == "*" || tag == etag)
+        });
+        Self {
+            status: if is_cached { 304 } else { 200 },
+            content_type: "image/svg+xml",
+            body: if is_cached {
+                Vec::new()
+            } else {
+                markup.as_bytes().to_vec()
+            },
+            etag: Some(etag),
+            allow: None,
+        }
+    }
+}
+
+/// Returns the 64-bit FNV-1a hash of some bytes.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Decode `%XX` escapes in a URL path segment.
+fn percent_decode(segment: &str) -> Option<String> {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = segment.get(index + 1..index + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Serves tile images over HTTP.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TileServer {
+    /// The layout for hands.
+    pub layout: Layout,
+}
+
+impl TileServer {
+    /// Create a server drawing hands with the given layout.
+    pub fn new(layout: Layout) -> Self {
+        Self { layout }
+    }
+
+    /// Answer a request, given its method, its URL path and query, and its
+    /// `If-None-Match` header.
+    pub fn respond(&self, method: &str, url: &str, if_none_match: Option<&str>) -> TileResponse {
+        if method != "GET" && method != "HEAD" {
+            return TileResponse {
+                allow: Some("GET, HEAD"),
+                ..TileResponse::text(405, "Only GET and HEAD are supported.")
+            };
+        }
+
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let segments = path
+            .trim_start_matches('/')
+            .split('/')
+            .map(percent_decode)
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+
+        let mut response = match segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            ["tiles", style, file] => self.tile(style, file, if_none_match),
+            ["hand", file] => self.hand(file, query, if_none_match),
+            _ => TileResponse::text(404, "Not found."),
+        };
+
+        if method == "HEAD" {
+            response.body.clear();
+        }
+        response
+    }
+
+    /// Answer a request for a single tile image.
+    fn tile(&self, style: &str, file: &str, if_none_match: Option<&str>) -> TileResponse {
+        let Some(style) = SvgStyle::from_name(style) else {
+            return TileResponse::text(404, format!("{style:?} is not an embedded style."));
+        };
+        file.strip_suffix(".svg")
+            .and_then(|name| style.get_svg_markup_by_name(name))
+            .map_or_else(
+                || TileResponse::text(404, format!("{file:?} is not a tile image.")),
+                |markup| TileResponse::svg(markup, if_none_match),
+            )
+    }
+
+    /// Answer a request for a rendered hand.
+    fn hand(&self, file: &str, query: &str, if_none_match: Option<&str>) -> TileResponse {
+        let Some(notation) = file.strip_suffix(".svg") else {
+            return TileResponse::text(404, format!("{file:?} is not an SVG image."));
+        };
+        let style = match query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("style="))
+        {
+            Some(name) => SvgStyle::from_name(name),
+            None => SvgStyle::ALL.first().copied(),
+        };
+        let Some(style) = style else {
+            return TileResponse::text(404, "The style is not embedded.");
+        };
+
+        match parse_hand(notation) {
+            Ok(hand) => {
+                TileResponse::svg(&render_hand(&hand, &[], style, self.layout), if_none_match)
+            }
+            Err(error) => TileResponse::text(400, error.to_string()),
+        }
+    }
+
+    /// Listen on the given address, answering requests one at a time until the
+    /// process exits.
+    pub fn serve(&self, address: impl ToSocketAddrs) -> io::Result<()> {
+        let server = tiny_http::Server::http(address).map_err(io::Error::other)?;
+
+        for request in server.incoming_requests() {
+            let if_none_match = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("If-None-Match"))
+                .map(|header| header.value.to_string());
+            let response = self.respond(
+                request.method().as_str(),
+                request.url(),
+                if_none_match.as_deref(),
+            );
+
+            let mut reply = tiny_http::Response::from_data(response.body)
+                .with_status_code(response.status)
+                .with_header(header("Content-Type", response.content_type))
+                .with_header(header("Cache-Control", "no-cache"));
+            if let Some(etag) = &response.etag {
+                reply.add_header(header("ETag", etag));
+            }
+            if let Some(allow) = response.allow {
+                reply.add_header(header("Allow", allow));
+            }
+            // A client that disconnects early only affects its own request.
+            let _ = request.respond(reply);
+        }
+        Ok(())
+    }
+}
+
+/// Build a response header from known-valid ASCII.
+fn header(field: &str, value: &str) -> tiny_http::Header {
+    tiny_http::Header::from_bytes(field, value).expect("Response headers should be ASCII.")
+}
+
+#[cfg(all(test, feature = "theme-dark", feature = "theme-light"))]
+mod test {
+    use super::*;
+
+    macro_rules! create_test {
+        ($name:ident, $method:literal, $url:literal, $status:literal) => {
+            #[test]
+            fn $name() {
+                let response = TileServer::default().respond($method, $url, None);
+                assert_eq!(response.status, $status, "{response:?}");
+            }
+        };
+    }
+
+    create_test!(tile, "GET", "/tiles/dark/tong-5.svg", 200);
+    create_test!(base_layer, "GET", "/tiles/light/base-back.svg", 200);
+    create_test!(unknown_style, "GET", "/tiles/sepia/tong-5.svg", 404);
+    create_test!(unknown_tile, "GET", "/tiles/dark/tong-10.svg", 404);
+    create_test!(not_svg, "GET", "/tiles/dark/tong-5.png", 404);
+    create_test!(hand, "GET", "/hand/123m456p.svg", 200);
+    create_test!(hand_with_style, "GET", "/hand/123m.svg?style=light", 200);
+    create_test!(hand_escaped, "GET", "/hand/123m%20456p.svg", 200);
+    create_test!(invalid_hand, "GET", "/hand/8z.svg", 400);
+    create_test!(unknown_path, "GET", "/", 404);
+    create_test!(post, "POST", "/tiles/dark/tong-5.svg", 405);
+
+    #[test]
+    fn tile_body() {
+        let response = TileServer::default().respond("GET", "/tiles/dark/tong-5.svg", None);
+
+        assert_eq!(response.content_type, "image/svg+xml");
+        assert_eq!(
+            response.body,
+            SvgStyle::DARK
+                .get_svg_markup_by_name("tong-5")
+                .unwrap()
+                .as_bytes()
+        );
+    }
+
+    #[test]
+    fn method_not_allowed() {
+        let response = TileServer::default().respond("DELETE", "/tiles/dark/tong-5.svg", None);
+
+        assert_eq!(response.status, 405);
+        assert_eq!(response.allow, Some("GET, HEAD"));
+    }
+
+    #[test]
+    fn etag_is_fnv1a() {
+        assert_eq!(
+            TileResponse::svg("", None).etag.as_deref(),
+            Some("\"cbf29ce484222325\"")
+        );
+        assert_eq!(
+            TileResponse::svg("a", None).etag.as_deref(),
+            Some("\"af63dc4c8601ec8c\"")
+        );
+    }
+
+    #[test]
+    fn head() {
+        let server = TileServer::default();
+        let get = server.respond("GET", "/hand/123m.svg", None);
+        let head = server.respond("HEAD", "/hand/123m.svg", None);
+
+        assert_eq!(head.status, 200);
+        assert_eq!(head.etag, get.etag);
+        assert!(head.body.is_empty());
+    }
+
+    #[test]
+    fn etag() {
+        let server = TileServer::default();
+        let url = "/tiles/light/honour-east.svg";
+        let etag = server.respond("GET", url, None).etag.unwrap();
+
+        assert_eq!(server.respond("GET", url, Some(&etag)).status, 304);
+        assert_eq!(
+            server
+                .respond("GET", url, Some(&format!("\"other\", W/{etag}")))
+                .status,
+            304
+        );
+        assert_eq!(server.respond("GET", url, Some("*")).status, 304);
+        assert_eq!(server.respond("GET", url, Some("\"other\"")).status, 200);
+        assert_ne!(
+            server
+                .respond("GET", "/tiles/dark/honour-east.svg", None)
+                .etag,
+            Some(etag)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-//! Build time generated modules with base64 encoded SVG contents.
+//! Build time generated modules with the minified SVG contents.
 //!
 //! This file is managed by the build script; do not edit it directly.
 
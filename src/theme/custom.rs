@@ -0,0 +1,193 @@
+//! Themes loaded at runtime.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use super::{required_svg_names, ThemeError, TileTheme};
+use crate::svg::encode_data_uri;
+
+/// The markup of a single SVG, and its data URI once requested.
+#[derive(Debug, Clone)]
+struct ThemeAsset {
+    markup: String,
+    data_uri: OnceLock<String>,
+}
+
+/// A theme loaded at runtime, such as a seasonal skin shipped separately from the
+/// binary.
+///
+/// Every name from [`required_svg_names`] must be present; any other SVGs are kept
+/// and can be looked up by name as well.
+#[derive(Debug, Clone)]
+pub struct CustomTheme {
+    assets: HashMap<String, ThemeAsset>,
+}
+
+impl CustomTheme {
+    /// Load a theme from pairs of SVG name and markup.
+    pub fn from_map<I, K, V>(svgs: I) -> Result<Self, ThemeError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let assets = svgs
+            .into_iter()
+            .map(|(name, markup)| {
+                let (name, markup) = (name.into(), markup.into());
+                if !markup.contains("<svg") {
+                    return Err(ThemeError::InvalidSvg(name));
+                }
+
+                Ok((
+                    name,
+                    ThemeAsset {
+                        markup,
+                        data_uri: OnceLock::new(),
+                    },
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        let missing = required_svg_names()
+            .filter(|name| !assets.contains_key(*name))
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(ThemeError::MissingSvgs(missing));
+        }
+
+        Ok(Self { assets })
+    }
+
+    /// Load a theme from the `<svg_name>.svg` files in a directory.
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let svgs = std::fs::read_dir(path)?
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.is_file()
+                    && path.extension()?.to_string_lossy().to_ascii_lowercase() == "svg"
+                {
+                    Some(path)
+                } else {
+                    None
+                }
+            })
+            .map(|path| {
+                let name = path
+                    .file_stem()
+                    .expect("Files with an extension should have a stem.")
+                    .to_string_lossy()
+                    .to_string();
+                Ok((name, std::fs::read_to_string(&path)?))
+            })
+            .collect::<Result<Vec<_>, ThemeError>>()?;
+
+        Self::from_map(svgs)
+    }
+}
+
+impl TileTheme for CustomTheme {
+    fn svg_markup_by_name(&self, name: &str) -> Option<&str> {
+        self.assets.get(name).map(|asset| asset.markup.as_str())
+    }
+
+    fn svg_data_by_name(&self, name: &str) -> Option<&str> {
+        self.assets.get(name).map(|asset| {
+            asset
+                .data_uri
+                .get_or_init(|| encode_data_uri(&asset.markup))
+                .as_str()
+        })
+    }
+}
+
+#[cfg(all(test, feature = "theme-light"))]
+mod test {
+    use super::*;
+    use crate::models::Tile;
+    use crate::svg::SvgStyle;
+    use crate::HasSvgData;
+
+    fn light_svgs() -> Vec<(String, String)> {
+        required_svg_names()
+            .map(|name| {
+                let markup = SvgStyle::LIGHT.get_svg_markup_by_name(name).unwrap();
+                (name.to_string(), markup.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn from_map() {
+        let theme = CustomTheme::from_map(light_svgs()).unwrap();
+
+        assert_eq!(
+            Tile::PLUM.theme_svg_markup(&theme),
+            Tile::PLUM.svg_markup(SvgStyle::LIGHT)
+        );
+        assert_eq!(
+            Tile::PLUM.theme_svg_data(&theme),
+            Tile::PLUM.svg_data(SvgStyle::LIGHT)
+        );
+    }
+
+    #[test]
+    fn extra_svgs() {
+        let mut svgs = light_svgs();
+        svgs.push(("logo".to_string(), "<svg/>".to_string()));
+        let theme = CustomTheme::from_map(svgs).unwrap();
+
+        assert_eq!(theme.svg_markup_by_name("logo"), Some("<svg/>"));
+    }
+
+    #[test]
+    fn missing_svgs() {
+        let svgs = light_svgs()
+            .into_iter()
+            .filter(|(name, _)| name != "wan-5" && name != "base-back");
+
+        match CustomTheme::from_map(svgs) {
+            Err(ThemeError::MissingSvgs(names)) => assert_eq!(names, ["wan-5", "base-back"]),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_svg() {
+        let mut svgs = light_svgs();
+        svgs[0].1 = "not an svg".to_string();
+
+        match CustomTheme::from_map(svgs) {
+            Err(ThemeError::InvalidSvg(name)) => assert_eq!(name, "wan-1"),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_dir() {
+        let dir = std::env::temp_dir().join(format!("mahjong-tiles-theme-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        light_svgs().iter().for_each(|(name, markup)| {
+            std::fs::write(dir.join(format!("{name}.svg")), markup).unwrap();
+        });
+        std::fs::write(dir.join("README.md"), "Not an SVG.").unwrap();
+
+        let theme = CustomTheme::from_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            theme.unwrap().svg_markup_by_name("season-winter"),
+            Tile::WINTER.svg_markup(SvgStyle::LIGHT)
+        );
+    }
+
+    #[test]
+    fn from_missing_dir() {
+        assert!(matches!(
+            CustomTheme::from_dir("/nonexistent/mahjong-tiles/theme"),
+            Err(ThemeError::Io(_))
+        ));
+    }
+}
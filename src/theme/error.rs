@@ -0,0 +1,32 @@
+//! Errors raised while loading custom themes.
+
+/// Errors raised while loading a [`CustomTheme`](super::CustomTheme).
+#[derive(Debug)]
+pub enum ThemeError {
+    /// The theme directory could not be read.
+    Io(std::io::Error),
+    /// The theme has no SVG for the listed names.
+    MissingSvgs(Vec<String>),
+    /// The file for the named SVG does not contain an `<svg>` element.
+    InvalidSvg(String),
+}
+
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Could not read the theme: {err}"),
+            Self::MissingSvgs(names) => {
+                write!(f, "Theme is missing SVGs for: {}.", names.join(", "))
+            }
+            Self::InvalidSvg(name) => write!(f, "{name}.svg is not an SVG document."),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<std::io::Error> for ThemeError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
@@ -0,0 +1,86 @@
+//! Tile themes, either embedded at build time as an [`SvgStyle`] or loaded at
+//! runtime as a [`CustomTheme`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use mahjong_tiles::{HasSvgData, SvgStyle, Tile};
+//! use mahjong_tiles::theme::{required_svg_names, CustomTheme};
+//!
+//! // Any source of `<svg_name>.svg` contents will do; see `CustomTheme::from_dir`.
+//! let theme = CustomTheme::from_map(required_svg_names().map(|name| {
+//!     let markup = SvgStyle::LIGHT.get_svg_markup_by_name(name).unwrap();
+//!     (name, markup.replace("#142896", "#1e8c3c"))
+//! }))
+//! .unwrap();
+//!
+//! let markup = Tile::EAST.theme_svg_markup(&theme).unwrap();
+//! assert!(markup.contains("#1e8c3c"));
+//! assert_eq!(
+//!     Tile::EAST.theme_svg_data(&SvgStyle::LIGHT),
+//!     Tile::EAST.svg_data(SvgStyle::LIGHT)
+//! );
+//! ```
+
+mod custom;
+pub use custom::*;
+
+mod error;
+pub use error::*;
+
+use crate::models::Tile;
+use crate::svg::{BaseLayer, SvgStyle};
+
+/// A set of tile artwork, looked up by SVG name.
+///
+/// The names are those of [`IsTile::svg_name`](crate::IsTile::svg_name) and [`BaseLayer::svg_name`].
+pub trait TileTheme {
+    /// Get the raw SVG markup for the given name.
+    fn svg_markup_by_name(&self, name: &str) -> Option<&str>;
+
+    /// Get the SVG data for the given name, as a base64 data URI.
+    fn svg_data_by_name(&self, name: &str) -> Option<&str>;
+}
+
+impl TileTheme for SvgStyle {
+    fn svg_markup_by_name(&self, name: &str) -> Option<&str> {
+        self.get_svg_markup_by_name(name)
+    }
+
+    fn svg_data_by_name(&self, name: &str) -> Option<&str> {
+        self.get_svg_by_name(name)
+    }
+}
+
+/// Returns the SVG names a theme needs: one for every kind of [`Tile`], followed by
+/// the [`BaseLayer`]s.
+pub fn required_svg_names() -> impl Iterator<Item = &'static str> {
+    (0..Tile::KINDS)
+        .filter_map(Tile::from_id)
+        .filter_map(|tile| tile.svg_name())
+        .chain(
+            [BaseLayer::FRONT, BaseLayer::BACK, BaseLayer::BLANK]
+                .into_iter()
+                .map(|layer| layer.svg_name()),
+        )
+}
+
+#[cfg(test)]
+mod test_required_svg_names {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "theme-dark", feature = "theme-light"))]
+    fn builtin_styles_are_complete() {
+        [SvgStyle::DARK, SvgStyle::LIGHT].iter().for_each(|style| {
+            required_svg_names().for_each(|name| {
+                assert!(style.svg_markup_by_name(name).is_some(), "{name}");
+            })
+        });
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(required_svg_names().count(), Tile::KINDS as usize + 3);
+    }
+}
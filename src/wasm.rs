@@ -0,0 +1,143 @@
+//! WebAssembly bindings through [wasm-bindgen](https://github.com/rustwasm/wasm-bindgen).
+//!
+//! Build the `cdylib` with `cargo rustc --lib --target wasm32-unknown-unknown
+//! --features wasm --crate-type cdylib`, then run `wasm-bindgen` over it for a
+//! JavaScript package with TypeScript definitions. Hands cross the boundary as a `Uint8Array` of
+//! [`Tile::id`]s, and styles as the names from [`SvgStyle::name`].
+//!
+//! ```js
+//! import { Tile, parseHand, shanten, waits } from "mahjong_tiles";
+//!
+//! const hand = parseHand("23m456p789s11155z");
+//! shanten(hand); // 0
+//! waits(hand).map((id) => Tile.fromId(id).name); // ["一万", "四万"]
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::hand;
+use crate::models::{IsTile, Tile};
+use crate::svg::{HasSvgData, SvgStyle};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT: &'static str = r#"
+/** The identifier of a tile, from 0 to 41; see `Tile.id`. */
+export type TileId = number;
+"#;
+
+/// Convert tile identifiers from JavaScript into tiles.
+fn tiles(ids: &[u8]) -> Result<Vec<Tile>, JsError> {
+    ids.iter()
+        .map(|id| {
+            Tile::from_id(*id).ok_or_else(|| JsError::new(&format!("{id} is not a tile id.")))
+        })
+        .collect()
+}
+
+/// Convert tiles into identifiers for JavaScript.
+fn ids(tiles: &[Tile]) -> Vec<u8> {
+    tiles.iter().map(Tile::id).collect()
+}
+
+/// Look up an embedded style by name.
+fn style(name: &str) -> Result<SvgStyle, JsError> {
+    SvgStyle::from_name(name)
+        .ok_or_else(|| JsError::new(&format!("{name:?} is not an embedded style.")))
+}
+
+/// A single tile.
+#[wasm_bindgen(js_name = Tile)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmTile(Tile);
+
+#[wasm_bindgen(js_class = Tile)]
+impl WasmTile {
+    /// Create a tile from its identifier, or `undefined` if out of range.
+    #[wasm_bindgen(js_name = fromId)]
+    pub fn from_id(id: u8) -> Option<WasmTile> {
+        Tile::from_id(id).map(Self)
+    }
+
+    /// Create a tile from its name in Chinese or Japanese, e.g. `"三萬"` or `"ピン5"`.
+    #[wasm_bindgen(js_name = fromName)]
+    pub fn from_name(name: &str) -> Result<WasmTile, JsError> {
+        Ok(Self(Tile::from_name(name)?))
+    }
+
+    /// The identifier of this tile.
+    #[wasm_bindgen(getter, unchecked_return_type = "TileId")]
+    pub fn id(&self) -> u8 {
+        self.0.id()
+    }
+
+    /// The Chinese name of this tile.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.0
+            .unique_name()
+            .expect("Tiles from names should have Chinese names.")
+    }
+
+    /// The name of the artwork for this tile, e.g. `"tong-5"`.
+    #[wasm_bindgen(getter, js_name = svgName)]
+    pub fn svg_name(&self) -> String {
+        self.0
+            .svg_name()
+            .expect("Tiles from identifiers should have SVG names.")
+            .to_string()
+    }
+
+    /// The character for this tile in the Unicode Mahjong Tiles block.
+    #[wasm_bindgen(getter)]
+    pub fn unicode(&self) -> String {
+        self.0
+            .to_unicode()
+            .expect("Tiles from names should have Unicode characters.")
+            .to_string()
+    }
+
+    /// The artwork for this tile as a base64 data URI, in the named style.
+    #[wasm_bindgen(js_name = svgData)]
+    pub fn svg_data(&self, style_name: &str) -> Result<Option<String>, JsError> {
+        Ok(self.0.svg_data(style(style_name)?).map(String::from))
+    }
+}
+
+/// The names of the styles embedded in this build, e.g. `["dark", "light"]`.
+#[wasm_bindgen(js_name = svgStyles)]
+pub fn svg_styles() -> Vec<String> {
+    SvgStyle::ALL
+        .iter()
+        .map(|style| style.name().to_string())
+        .collect()
+}
+
+/// The artwork for a tile or base layer by its SVG name, as a base64 data URI.
+#[wasm_bindgen(js_name = svgData)]
+pub fn svg_data(name: &str, style_name: &str) -> Result<Option<String>, JsError> {
+    Ok(style(style_name)?.get_svg_by_name(name).map(String::from))
+}
+
+/// Parse a hand in mpsz notation into tile identifiers; see [`hand::parse_hand`].
+#[wasm_bindgen(js_name = parseHand, unchecked_return_type = "Uint8Array")]
+pub fn parse_hand(notation: &str) -> Result<Vec<u8>, JsError> {
+    Ok(ids(&hand::parse_hand(notation)?))
+}
+
+/// Format tile identifiers as a hand in mpsz notation.
+#[wasm_bindgen(js_name = formatHand)]
+pub fn format_hand(hand: &[u8]) -> Result<String, JsError> {
+    Ok(hand::format_hand(&tiles(hand)?)?)
+}
+
+/// The shanten of a hand of tile identifiers; see [`hand::shanten`].
+#[wasm_bindgen]
+pub fn shanten(hand: &[u8]) -> Result<i8, JsError> {
+    Ok(hand::shanten(&tiles(hand)?)?)
+}
+
+/// The tile identifiers that complete a ready hand; see [`hand::waits`].
+#[wasm_bindgen(unchecked_return_type = "Uint8Array")]
+pub fn waits(hand: &[u8]) -> Result<Vec<u8>, JsError> {
+    Ok(ids(&hand::waits(&tiles(hand)?)?))
+}
@@ -0,0 +1,187 @@
+//! Invariants of the tile model, checked over every kind of tile, and over arbitrary
+//! hands with [proptest](https://docs.rs/proptest).
+#![cfg(feature = "std")]
+
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use mahjong_tiles::hand::{format_hand, parse_hand};
+use mahjong_tiles::{BaseLayer, HasSvgData, IsTile, SvgStyle, Tile};
+use proptest::prelude::*;
+
+/// Every kind of tile, in the order of [`Tile::id`].
+fn all_tiles() -> Vec<Tile> {
+    (0..Tile::KINDS).filter_map(Tile::from_id).collect()
+}
+
+/// Number tiles whose values are out of range, which the public variants still allow.
+const OUT_OF_RANGE: [Tile; 6] = [
+    Tile::WAN(0),
+    Tile::TONG(0),
+    Tile::TONG(10),
+    Tile::TIAO(10),
+    Tile::WAN(u8::MAX),
+    Tile::TIAO(u8::MAX),
+];
+
+/// A [`Hasher`] that records the bytes written to it, so that hashes can be compared
+/// without collisions.
+#[derive(Default)]
+struct RecordingHasher(Vec<u8>);
+
+impl Hasher for RecordingHasher {
+    /// Returns the 64-bit FNV-1a hash of the recorded bytes.
+    fn finish(&self) -> u64 {
+        self.0.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+/// Get the bytes a tile feeds into a [`Hasher`].
+fn hash_input(tile: &Tile) -> Vec<u8> {
+    let mut hasher = RecordingHasher::default();
+    tile.hash(&mut hasher);
+    hasher.0
+}
+
+fn tile_strategy() -> impl Strategy<Value = Tile> {
+    (0..Tile::KINDS).prop_map(|id| Tile::from_id(id).unwrap())
+}
+
+#[test]
+fn every_id_is_a_tile() {
+    let tiles = all_tiles();
+
+    assert_eq!(tiles.len(), usize::from(Tile::KINDS));
+    assert!(tiles
+        .iter()
+        .enumerate()
+        .all(|(id, tile)| usize::from(tile.id()) == id));
+    assert_eq!(Tile::from_id(Tile::KINDS), None);
+}
+
+#[test]
+fn hash_is_injective() {
+    let inputs = all_tiles().iter().map(hash_input).collect::<HashSet<_>>();
+
+    assert_eq!(inputs.len(), usize::from(Tile::KINDS));
+}
+
+#[test]
+fn hash_finishes_consistently() {
+    let mut hasher = RecordingHasher::default();
+    Tile::TONG(5).hash(&mut hasher);
+
+    assert_eq!(hasher.finish(), hasher.finish());
+    assert_ne!(hasher.finish(), RecordingHasher::default().finish());
+}
+
+#[test]
+fn arithmetic_stays_in_suit() {
+    for tile in all_tiles().into_iter().chain(OUT_OF_RANGE) {
+        let value = tile.value().filter(|v| (1..=9).contains(v));
+        for step in 0..=u8::MAX {
+            for (result, expected) in [
+                (tile + step, value.and_then(|v| v.checked_add(step))),
+                (tile - step, value.and_then(|v| v.checked_sub(step))),
+            ] {
+                let expected = expected.filter(|v| (1..=9).contains(v));
+                match result {
+                    Some(result) => {
+                        assert_eq!(result.category(), tile.category(), "{tile:?} by {step}");
+                        assert_eq!(result.value(), expected, "{tile:?} by {step}");
+                    }
+                    None => assert_eq!(expected, None, "{tile:?} by {step}"),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn arithmetic_rejects_out_of_range() {
+    for tile in OUT_OF_RANGE {
+        for step in 0..=u8::MAX {
+            assert_eq!(tile + step, None, "{tile:?} + {step}");
+            assert_eq!(tile - step, None, "{tile:?} - {step}");
+        }
+    }
+}
+
+#[test]
+fn arithmetic_is_reversible() {
+    for tile in all_tiles().into_iter().chain(OUT_OF_RANGE) {
+        for step in 0..=9 {
+            if let Some(next) = tile + step {
+                assert_eq!(next - step, Some(tile));
+            }
+            if let Some(previous) = tile - step {
+                assert_eq!(previous + step, Some(tile));
+            }
+        }
+    }
+}
+
+#[test]
+fn every_tile_has_svg_in_every_style() {
+    for style in SvgStyle::ALL {
+        for tile in all_tiles() {
+            let markup = tile.svg_markup(*style);
+            assert!(
+                markup.is_some_and(|markup| markup.contains("<svg")),
+                "{tile:?} has no {} markup",
+                style.name()
+            );
+            assert!(tile.svg_data(*style).is_some());
+        }
+        for layer in [BaseLayer::FRONT, BaseLayer::BACK, BaseLayer::BLANK] {
+            assert!(style.get_svg_markup_by_name(layer.svg_name()).is_some());
+        }
+    }
+}
+
+#[test]
+fn names_are_unique() {
+    let tiles = all_tiles();
+    let unique_names = tiles
+        .iter()
+        .filter_map(Tile::unique_name)
+        .collect::<HashSet<_>>();
+    let svg_names = tiles.iter().map(Tile::svg_name).collect::<HashSet<_>>();
+
+    assert_eq!(unique_names.len(), tiles.len());
+    assert_eq!(svg_names.len(), tiles.len());
+    for tile in tiles {
+        assert_eq!(Tile::from_name(&tile.unique_name().unwrap()), Ok(tile));
+    }
+}
+
+#[test]
+fn every_tile_formats_and_parses() {
+    for tile in all_tiles() {
+        assert_eq!(parse_hand(&format_hand(&[tile]).unwrap()), Ok(vec![tile]));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_inverts_format(tiles in prop::collection::vec(tile_strategy(), 0..=18)) {
+        let notation = format_hand(&tiles).unwrap();
+        let mut sorted = tiles.clone();
+        sorted.sort_by_key(Tile::id);
+
+        prop_assert_eq!(parse_hand(&notation), Ok(sorted));
+    }
+
+    #[test]
+    fn format_is_canonical(tiles in prop::collection::vec(tile_strategy(), 0..=18)) {
+        let notation = format_hand(&tiles).unwrap();
+
+        prop_assert_eq!(format_hand(&parse_hand(&notation).unwrap()), Ok(notation));
+    }
+}
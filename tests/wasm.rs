@@ -0,0 +1,40 @@
+//! Tests for the WebAssembly bindings, run in a headless browser or Node.js with
+//! `wasm-pack test --node --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use mahjong_tiles::wasm::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn tile() {
+    let tile = WasmTile::from_id(13).unwrap();
+
+    assert_eq!(tile.id(), 13);
+    assert_eq!(tile.name(), "五筒");
+    assert_eq!(tile.svg_name(), "tong-5");
+    assert_eq!(tile.unicode(), "🀝");
+    assert_eq!(WasmTile::from_name("五筒").unwrap(), tile);
+    assert!(WasmTile::from_id(42).is_none());
+}
+
+#[wasm_bindgen_test]
+fn svg_lookup() {
+    let tile = WasmTile::from_id(27).unwrap();
+    let data = tile.svg_data("light").unwrap().unwrap();
+
+    assert!(data.starts_with("data:image/svg+xml;base64,"));
+    assert_eq!(svg_data("honour-east", "light").unwrap(), Some(data));
+    assert!(svg_styles().contains(&"dark".to_string()));
+    assert!(tile.svg_data("sepia").is_err());
+}
+
+#[wasm_bindgen_test]
+fn hand_analysis() {
+    let hand = parse_hand("23m456p789s11155z").unwrap();
+
+    assert_eq!(format_hand(&hand).unwrap(), "23m456p789s11155z");
+    assert_eq!(shanten(&hand).unwrap(), 0);
+    assert_eq!(waits(&hand).unwrap(), vec![0, 3]);
+    assert!(parse_hand("8z").is_err());
+    assert!(shanten(&[42]).is_err());
+}